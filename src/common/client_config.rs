@@ -0,0 +1,220 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+
+/// provides HTTP version options of the requests.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HttpVersion {
+    /// lets curl decide the version.
+    Any,
+    V10,
+    V11,
+    /// HTTP/2 with fallback to HTTP/1.1.
+    V2,
+    /// HTTP/2 for https and HTTP/1.1 for http urls.
+    V2Tls,
+}
+
+impl HttpVersion {
+    pub(crate) fn to_curl(self) -> curl::easy::HttpVersion {
+        match self {
+            Self::Any => curl::easy::HttpVersion::Any,
+            Self::V10 => curl::easy::HttpVersion::V10,
+            Self::V11 => curl::easy::HttpVersion::V11,
+            Self::V2 => curl::easy::HttpVersion::V2,
+            Self::V2Tls => curl::easy::HttpVersion::V2TLS,
+        }
+    }
+}
+
+
+/// contains the network settings applied to every request made with an [`Evds`](struct@crate::common::Evds).
+///
+/// Default configuration has no timeouts, no proxy, uses the CA bundle of the system, identifies itself as
+/// `tcmb_evds/<version>`, lets curl decide the HTTP version and keeps up to 4 idle connections alive for reuse.
+///
+/// - *connect timeout* limits the time spent on establishing the connection.
+/// - *read timeout* aborts the transfer when no data is received during the given time.
+/// - *total timeout* limits the whole transfer of a single attempt.
+/// - *max idle connections* limits the number of kept alive connections. Zero disables connection reuse.
+/// - *max url length* and *max series per request* limit the size of a data series request. Series exceeding the
+///   limits are requested in several parts whose responses are merged by date. Defaults are 2000 characters and no
+///   series limit.
+///
+/// # Example
+///
+/// ```
+///     use std::time::Duration;
+///     use tcmb_evds::common::{ClientConfig, HttpVersion};
+///
+///
+///     let mut client_config = ClientConfig::new();
+///
+///     client_config.change_connect_timeout(Some(Duration::from_secs(5)));
+///     client_config.change_total_timeout(Some(Duration::from_secs(60)));
+///     client_config.change_proxy(Some("http://proxy.example.com:8080"));
+///     client_config.change_proxy_credentials(Some(("user", "password")));
+///     client_config.change_ca_bundle(Some("/etc/ssl/certs/internal-ca.pem"));
+///     client_config.change_http_version(HttpVersion::V11);
+/// ```
+#[derive(Clone)]
+pub struct ClientConfig {
+    connect_timeout: Option<Duration>,
+    read_timeout: Option<Duration>,
+    total_timeout: Option<Duration>,
+    proxy: Option<String>,
+    proxy_credentials: Option<(String, String)>,
+    ca_bundle: Option<PathBuf>,
+    user_agent: String,
+    http_version: HttpVersion,
+    max_idle_connections: usize,
+    max_url_length: usize,
+    max_series_per_request: Option<usize>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> ClientConfig {
+        ClientConfig {
+            connect_timeout: None,
+            read_timeout: None,
+            total_timeout: None,
+            proxy: None,
+            proxy_credentials: None,
+            ca_bundle: None,
+            user_agent: format!("tcmb_evds/{}", env!("CARGO_PKG_VERSION")),
+            http_version: HttpVersion::Any,
+            max_idle_connections: 4,
+            max_url_length: 2000,
+            max_series_per_request: None,
+        }
+    }
+}
+
+impl ClientConfig {
+    /// creates default client configuration.
+    pub fn new() -> ClientConfig {
+        ClientConfig::default()
+    }
+
+    /// changes the time limit of establishing connection. `None` removes the limit.
+    pub fn change_connect_timeout(&mut self, connect_timeout: Option<Duration>) {
+        self.connect_timeout = connect_timeout;
+    }
+
+    /// changes the time limit of waiting for incoming data. `None` removes the limit.
+    pub fn change_read_timeout(&mut self, read_timeout: Option<Duration>) {
+        self.read_timeout = read_timeout;
+    }
+
+    /// changes the time limit of a whole transfer. `None` removes the limit.
+    pub fn change_total_timeout(&mut self, total_timeout: Option<Duration>) {
+        self.total_timeout = total_timeout;
+    }
+
+    /// changes proxy url such as "http://proxy.example.com:8080". `None` makes requests direct.
+    pub fn change_proxy(&mut self, proxy: Option<&str>) {
+        self.proxy = proxy.map(|proxy| proxy.to_string());
+    }
+
+    /// changes proxy user name and password given in order.
+    pub fn change_proxy_credentials(&mut self, proxy_credentials: Option<(&str, &str)>) {
+        self.proxy_credentials =
+            proxy_credentials.map(|(user_name, password)| (user_name.to_string(), password.to_string()));
+    }
+
+    /// changes path of the CA bundle used to verify the server. `None` uses the bundle of the system.
+    pub fn change_ca_bundle<P: AsRef<Path>>(&mut self, ca_bundle: Option<P>) {
+        self.ca_bundle = ca_bundle.map(|path| path.as_ref().to_path_buf());
+    }
+
+    /// changes user agent header value.
+    pub fn change_user_agent(&mut self, user_agent: &str) {
+        self.user_agent = user_agent.to_string();
+    }
+
+    /// changes HTTP version of the requests.
+    pub fn change_http_version(&mut self, http_version: HttpVersion) {
+        self.http_version = http_version;
+    }
+
+    /// changes the number of idle connections kept alive to be reused by the following requests.
+    ///
+    /// Zero makes each request open a new connection.
+    pub fn change_max_idle_connections(&mut self, max_idle_connections: usize) {
+        self.max_idle_connections = max_idle_connections;
+    }
+
+    /// changes the url length that a data series request is split above.
+    pub fn change_max_url_length(&mut self, max_url_length: usize) {
+        self.max_url_length = max_url_length;
+    }
+
+    /// changes the number of series that a data series request is split above. `None` removes the limit.
+    pub fn change_max_series_per_request(&mut self, max_series_per_request: Option<usize>) {
+        self.max_series_per_request = max_series_per_request;
+    }
+
+    pub(crate) fn get_connect_timeout(&self) -> Option<Duration> {
+        self.connect_timeout
+    }
+
+    pub(crate) fn get_read_timeout(&self) -> Option<Duration> {
+        self.read_timeout
+    }
+
+    pub(crate) fn get_total_timeout(&self) -> Option<Duration> {
+        self.total_timeout
+    }
+
+    pub(crate) fn get_proxy(&self) -> Option<&str> {
+        self.proxy.as_deref()
+    }
+
+    pub(crate) fn get_proxy_credentials(&self) -> Option<(&str, &str)> {
+        self.proxy_credentials.as_ref().map(|(user_name, password)| (user_name.as_str(), password.as_str()))
+    }
+
+    pub(crate) fn get_ca_bundle(&self) -> Option<&Path> {
+        self.ca_bundle.as_deref()
+    }
+
+    pub(crate) fn get_user_agent(&self) -> &str {
+        &self.user_agent
+    }
+
+    pub(crate) fn get_http_version(&self) -> HttpVersion {
+        self.http_version
+    }
+
+    pub(crate) fn get_max_idle_connections(&self) -> usize {
+        self.max_idle_connections
+    }
+
+    pub(crate) fn get_max_url_length(&self) -> usize {
+        self.max_url_length
+    }
+
+    pub(crate) fn get_max_series_per_request(&self) -> Option<usize> {
+        self.max_series_per_request
+    }
+}
+
+impl fmt::Debug for ClientConfig {
+    /// formats the configuration without revealing the proxy password.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ClientConfig")
+            .field("connect_timeout", &self.connect_timeout)
+            .field("read_timeout", &self.read_timeout)
+            .field("total_timeout", &self.total_timeout)
+            .field("proxy", &self.proxy)
+            .field("proxy_credentials", &self.proxy_credentials.as_ref().map(|(user_name, _)| (user_name, "***")))
+            .field("ca_bundle", &self.ca_bundle)
+            .field("user_agent", &self.user_agent)
+            .field("http_version", &self.http_version)
+            .field("max_idle_connections", &self.max_idle_connections)
+            .field("max_url_length", &self.max_url_length)
+            .field("max_series_per_request", &self.max_series_per_request)
+            .finish()
+    }
+}
@@ -0,0 +1,108 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+
+#[cfg(feature = "async_mode")]
+use curl::easy::Easy2;
+#[cfg(feature = "sync_mode")]
+use curl::easy::Easy;
+
+#[cfg(feature = "async_mode")]
+use crate::request_async::Collector;
+
+
+/// contains request and connection counters of an [`Evds`](struct@crate::common::Evds) to measure connection reuse.
+///
+/// Each request reusing a kept alive connection does not increase `new_connections`. Therefore, the difference between
+/// `requests` and `new_connections` is the number of saved TCP and TLS handshakes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionStats {
+    /// number of performed transfers including repeated attempts.
+    pub requests: u64,
+    /// number of connections that had to be newly established.
+    pub new_connections: u64,
+}
+
+
+/// keeps idle curl handles together with their open connections to be reused by the following requests.
+///
+/// A handle is taken from the pool for a request and given back when the request ends. Handles are reset before each
+/// request, which clears options but keeps the connection and TLS session caches.
+pub(crate) struct ConnectionPool {
+    #[cfg(feature = "async_mode")]
+    async_handles: Mutex<Vec<Easy2<Collector>>>,
+    #[cfg(feature = "sync_mode")]
+    sync_handles: Mutex<Vec<Easy>>,
+    requests: AtomicU64,
+    new_connections: AtomicU64,
+}
+
+impl ConnectionPool {
+    pub(crate) fn new() -> ConnectionPool {
+        ConnectionPool {
+            #[cfg(feature = "async_mode")]
+            async_handles: Mutex::new(Vec::new()),
+            #[cfg(feature = "sync_mode")]
+            sync_handles: Mutex::new(Vec::new()),
+            requests: AtomicU64::new(0),
+            new_connections: AtomicU64::new(0),
+        }
+    }
+
+    #[cfg(feature = "async_mode")]
+    pub(crate) fn take_async_handle(&self) -> Easy2<Collector> {
+        let handle = match self.async_handles.lock() {
+            Ok(mut handles) => handles.pop(),
+            Err(_) => None,
+        };
+
+        handle.unwrap_or_else(|| Easy2::new(Collector(Vec::new())))
+    }
+
+    /// gives the handle back unless the pool already keeps `max_idle` handles.
+    #[cfg(feature = "async_mode")]
+    pub(crate) fn give_back_async_handle(&self, handle: Easy2<Collector>, max_idle: usize) {
+        if let Ok(mut handles) = self.async_handles.lock() {
+            if handles.len() < max_idle { handles.push(handle); }
+        }
+    }
+
+    #[cfg(feature = "sync_mode")]
+    pub(crate) fn take_sync_handle(&self) -> Easy {
+        let handle = match self.sync_handles.lock() {
+            Ok(mut handles) => handles.pop(),
+            Err(_) => None,
+        };
+
+        handle.unwrap_or_else(Easy::new)
+    }
+
+    /// gives the handle back unless the pool already keeps `max_idle` handles.
+    #[cfg(feature = "sync_mode")]
+    pub(crate) fn give_back_sync_handle(&self, handle: Easy, max_idle: usize) {
+        if let Ok(mut handles) = self.sync_handles.lock() {
+            if handles.len() < max_idle { handles.push(handle); }
+        }
+    }
+
+    /// drops all idle handles and closes their connections.
+    pub(crate) fn clear(&self) {
+        #[cfg(feature = "async_mode")]
+        if let Ok(mut handles) = self.async_handles.lock() { handles.clear(); }
+
+        #[cfg(feature = "sync_mode")]
+        if let Ok(mut handles) = self.sync_handles.lock() { handles.clear(); }
+    }
+
+    /// records a performed transfer and the number of connections it established.
+    pub(crate) fn record_transfer(&self, new_connections: u64) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        self.new_connections.fetch_add(new_connections, Ordering::Relaxed);
+    }
+
+    pub(crate) fn stats(&self) -> ConnectionStats {
+        ConnectionStats {
+            requests: self.requests.load(Ordering::Relaxed),
+            new_connections: self.new_connections.load(Ordering::Relaxed),
+        }
+    }
+}
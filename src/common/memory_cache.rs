@@ -0,0 +1,275 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::common::response_cache::normalise_url;
+use crate::date::calendar::{self, SECONDS_PER_DAY};
+use crate::error::ReturnError;
+
+
+/// contains hit and miss counters of a [`MemoryCache`](struct@MemoryCache).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CacheStats {
+    /// number of requests served from the cache.
+    pub hits: u64,
+    /// number of requests that were not cached or whose cached response was outdated.
+    pub misses: u64,
+}
+
+
+struct Entry {
+    response: String,
+    publication: i64,
+    last_use: u64,
+}
+
+/// keeps entries by key and their keys by the order of use, so that the least recently used key is the first one.
+struct Lru {
+    entries: HashMap<String, Entry>,
+    uses: BTreeMap<u64, String>,
+    next_use: u64,
+}
+
+impl Lru {
+    fn touch(&mut self, key: &str) {
+        let next_use = self.next_use;
+
+        if let Some(entry) = self.entries.get_mut(key) {
+            self.uses.remove(&entry.last_use);
+            entry.last_use = next_use;
+            self.uses.insert(next_use, key.to_string());
+            self.next_use += 1;
+        }
+    }
+
+    fn remove(&mut self, key: &str) {
+        if let Some(entry) = self.entries.remove(key) {
+            self.uses.remove(&entry.last_use);
+        }
+    }
+}
+
+
+/// keeps a bounded number of recently used responses in memory in front of the transport layer.
+///
+/// The cache is thread safe and shared by its clones, so that parallel workers benefit from the responses of each
+/// other. When the cache is full, the least recently used response is dropped.
+///
+/// The central bank publishes the rates of a new business day at about 15:30 Istanbul time. Responses cached before
+/// the latest publication time are treated as outdated and requested again.
+///
+/// # Example
+///
+/// ```
+///     use tcmb_evds::common::MemoryCache;
+///
+///
+///     let mut memory_cache = MemoryCache::from(1000);
+///
+///     memory_cache.change_publication_time(16, 0);
+///
+///     let stats = memory_cache.stats();
+///
+///     println!("{} hits and {} misses", stats.hits, stats.misses);
+/// ```
+#[derive(Clone)]
+pub struct MemoryCache {
+    capacity: usize,
+    publication_time: i64,
+    lru: Arc<Mutex<Lru>>,
+    hits: Arc<AtomicU64>,
+    misses: Arc<AtomicU64>,
+}
+
+impl MemoryCache {
+    /// creates an empty cache keeping up to `capacity` responses. Zero capacity is treated as 1.
+    pub fn from(capacity: usize) -> MemoryCache {
+        MemoryCache {
+            capacity: capacity.max(1),
+            publication_time: (15 * 60 + 30) * 60,
+            lru: Arc::new(Mutex::new(Lru { entries: HashMap::new(), uses: BTreeMap::new(), next_use: 0 })),
+            hits: Arc::new(AtomicU64::new(0)),
+            misses: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// changes the daily publication time in Istanbul time after which cached responses are requested again.
+    pub fn change_publication_time(&mut self, hour: u32, minute: u32) {
+        self.publication_time = ((hour.min(23) * 60 + minute.min(59)) * 60) as i64;
+    }
+
+    /// gives the maximum number of kept responses.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// gives the number of kept responses.
+    pub fn len(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    /// checks there is no kept response.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// gives hit and miss counters.
+    pub fn stats(&self) -> CacheStats {
+        CacheStats { hits: self.hits.load(Ordering::Relaxed), misses: self.misses.load(Ordering::Relaxed) }
+    }
+
+    /// drops all kept responses. Counters are kept.
+    pub fn clear(&self) {
+        let mut lru = self.lock();
+
+        lru.entries.clear();
+        lru.uses.clear();
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Lru> {
+        match self.lru.lock() {
+            Ok(lru) => lru,
+            Err(poisoned) => poisoned.into_inner(),
+        }
+    }
+
+    /// gives the number of the latest publication, which increases by one at each publication time.
+    fn publication_of(&self, istanbul_seconds: i64) -> i64 {
+        (istanbul_seconds - self.publication_time).div_euclid(SECONDS_PER_DAY)
+    }
+
+    /// gives the kept response of the url or requests it with the given function.
+    ///
+    /// The lock is not held during the request, so that other threads are not blocked by the network.
+    ///
+    /// # Error
+    ///
+    /// This function returns the error of the request which is not cached.
+    pub(crate) fn fetch<F>(&self, url: &str, request: F) -> Result<String, ReturnError>
+    where
+        F: FnOnce() -> Result<String, ReturnError>,
+    {
+        self.fetch_at(url, calendar::istanbul_seconds_now(), request)
+    }
+
+    fn fetch_at<F>(&self, url: &str, istanbul_seconds: i64, request: F) -> Result<String, ReturnError>
+    where
+        F: FnOnce() -> Result<String, ReturnError>,
+    {
+        let key = normalise_url(url);
+        let publication = self.publication_of(istanbul_seconds);
+
+        {
+            let mut lru = self.lock();
+
+            match lru.entries.get(&key).map(|entry| entry.publication == publication) {
+                Some(true) => {
+                    lru.touch(&key);
+                    self.hits.fetch_add(1, Ordering::Relaxed);
+
+                    return Ok(lru.entries[&key].response.clone());
+                },
+                Some(false) => lru.remove(&key),
+                None => {},
+            }
+        }
+
+        self.misses.fetch_add(1, Ordering::Relaxed);
+
+        let response = request()?;
+
+        let mut lru = self.lock();
+
+        lru.remove(&key);
+
+        while lru.entries.len() >= self.capacity {
+            let least_recently_used = match lru.uses.values().next() {
+                Some(key) => key.clone(),
+                None => break,
+            };
+
+            lru.remove(&least_recently_used);
+        }
+
+        let last_use = lru.next_use;
+        lru.next_use += 1;
+        lru.uses.insert(last_use, key.clone());
+        lru.entries.insert(key, Entry { response: response.clone(), publication, last_use });
+
+        Ok(response)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::thread;
+
+    use super::*;
+
+    // 13-12-2011 10:00 in Istanbul time.
+    const MORNING: i64 = 15_321 * SECONDS_PER_DAY + 10 * 60 * 60;
+
+    fn respond(text: &str) -> impl FnOnce() -> Result<String, ReturnError> + '_ {
+        move || Ok(text.to_string())
+    }
+
+    #[test]
+    fn should_count_hits_and_misses() {
+        let memory_cache = MemoryCache::from(10);
+
+        assert_eq!(Ok("usd".to_string()), memory_cache.fetch_at("series=USD&key=a", MORNING, respond("usd")));
+        assert_eq!(Ok("usd".to_string()), memory_cache.fetch_at("series=USD&key=b", MORNING, respond("new")));
+        assert!(memory_cache.fetch_at("series=EUR", MORNING, || Err(ReturnError::EmptyResponse)).is_err());
+
+        assert_eq!(CacheStats { hits: 1, misses: 2 }, memory_cache.stats());
+        assert_eq!(1, memory_cache.len());
+    }
+
+    #[test]
+    fn should_drop_least_recently_used() {
+        let memory_cache = MemoryCache::from(2);
+
+        memory_cache.fetch_at("series=A", MORNING, respond("a")).unwrap();
+        memory_cache.fetch_at("series=B", MORNING, respond("b")).unwrap();
+        memory_cache.fetch_at("series=A", MORNING, respond("new")).unwrap();
+        memory_cache.fetch_at("series=C", MORNING, respond("c")).unwrap();
+
+        assert_eq!(Ok("a".to_string()), memory_cache.fetch_at("series=A", MORNING, respond("new")));
+        assert_eq!(Ok("new".to_string()), memory_cache.fetch_at("series=B", MORNING, respond("new")));
+        assert_eq!(2, memory_cache.len());
+    }
+
+    #[test]
+    fn should_invalidate_after_publication_time() {
+        let memory_cache = MemoryCache::from(10);
+        let before_publication = MORNING + 5 * 60 * 60 + 29 * 60;
+        let after_publication = MORNING + 5 * 60 * 60 + 31 * 60;
+        let next_morning = MORNING + SECONDS_PER_DAY;
+
+        memory_cache.fetch_at("series=USD", MORNING, respond("old")).unwrap();
+
+        assert_eq!(Ok("old".to_string()), memory_cache.fetch_at("series=USD", before_publication, respond("new")));
+        assert_eq!(Ok("new".to_string()), memory_cache.fetch_at("series=USD", after_publication, respond("new")));
+        assert_eq!(Ok("new".to_string()), memory_cache.fetch_at("series=USD", next_morning, respond("newer")));
+    }
+
+    #[test]
+    fn should_share_entries_between_threads() {
+        let memory_cache = MemoryCache::from(10);
+
+        memory_cache.fetch_at("series=USD", MORNING, respond("usd")).unwrap();
+
+        let workers: Vec<_> = (0..4).map(|_| {
+            let memory_cache = memory_cache.clone();
+
+            thread::spawn(move || memory_cache.fetch_at("series=USD", MORNING, respond("new")).unwrap())
+        }).collect();
+
+        for worker in workers {
+            assert_eq!("usd", worker.join().unwrap());
+        }
+
+        assert_eq!(CacheStats { hits: 4, misses: 1 }, memory_cache.stats());
+    }
+}
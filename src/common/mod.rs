@@ -1,3 +1,20 @@
+/// contains the retry policy applied by the transport layer to failed requests.
+mod retry_policy;
+/// contains network settings applied by the transport layer to each request.
+mod client_config;
+/// keeps curl handles and their connections alive between requests.
+mod connection_pool;
+/// contains the token bucket limiting the request rate.
+mod rate_limiter;
+/// bundles the settings and the shared state of the transport layer.
+mod transport;
+/// stores responses on disk with a time to live per kind of data.
+mod response_cache;
+/// keeps recently used responses in memory.
+mod memory_cache;
+/// contains requests composed without being sent.
+mod request;
+
 use std::cmp;
 
 use crate::error::ReturnError;
@@ -9,10 +26,21 @@ use crate::request_async;
 #[cfg(feature = "sync_mode")]
 use crate::request_sync;
 
+pub use self::retry_policy::{ErrorClass, RetryPolicy};
+pub use self::client_config::{ClientConfig, HttpVersion};
+pub use self::connection_pool::ConnectionStats;
+pub use self::rate_limiter::RateLimiter;
+pub use self::response_cache::{CacheMode, ResponseCache};
+pub use self::memory_cache::{CacheStats, MemoryCache};
+pub use self::request::Request;
+pub(crate) use self::connection_pool::ConnectionPool;
+pub(crate) use self::transport::Transport;
+
 
 /// provides users an option menu to choose one of the return format.
 ///
 /// Users are expected to use appropriate format for related request.
+#[derive(Clone)]
 pub enum ReturnFormat {
     /// Comma Separated Values format.
     Csv,
@@ -45,14 +73,14 @@ impl traits::MakingUrlFormat for ReturnFormat {
 ///
 /// To check validity of the given api key, users need to create an api key variable via 
 /// [`ApiKey::from`](fn@ApiKey::from).
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct ApiKey(String);
 
 impl<'a> ApiKey {
-    fn change(&mut self, new_key: &'a str) -> Result<(), ReturnError> {
+    fn change(&mut self, new_key: &'a str, transport: &Transport) -> Result<(), ReturnError> {
         let api_key = ApiKey(new_key.to_string());
 
-        api_key.is_api_key_valid()?;
+        api_key.is_api_key_valid(transport)?;
         
         self.0 = new_key.to_string();
 
@@ -60,22 +88,23 @@ impl<'a> ApiKey {
     }
 
     #[cfg(feature = "async_mode")]
-    fn check_api_key_validity_async(reference_url: String) -> Result<(), ReturnError> {
-        match request_async::do_request(&reference_url) {
+    fn check_api_key_validity_async(reference_url: String, transport: &Transport) -> Result<(), ReturnError> {
+        match request_async::do_request(&reference_url, transport) {
             Ok(_) => Ok(()),
             Err(_) => Err(ReturnError::InvalidApiKeyOrBadInternetConnection),
         }
     }
 
     #[cfg(feature = "sync_mode")]
-    fn check_api_key_validity_sync(reference_url: String) -> Result<(), ReturnError> {
-        match request_sync::do_request(&reference_url) {
+    fn check_api_key_validity_sync(reference_url: String, transport: &Transport) -> Result<(), ReturnError> {
+        match request_sync::do_request(&reference_url, transport) {
             Ok(_) => Ok(()),
             Err(_) => Err(ReturnError::InvalidApiKeyOrBadInternetConnection),
         }
     }
 
-    fn is_api_key_valid(&self) -> Result<(), ReturnError> {
+    /// checks the api key with a request made by the given transport, so that its client configuration is applied.
+    fn is_api_key_valid(&self, transport: &Transport) -> Result<(), ReturnError> {
         // The string below is divided into two due to the convention of horizontal width which is 120 characters. 
         let reference_url = 
         format!(
@@ -85,16 +114,22 @@ impl<'a> ApiKey {
         );
     
         #[cfg(feature = "async_mode")]
-        return ApiKey::check_api_key_validity_async(reference_url);
+        return ApiKey::check_api_key_validity_async(reference_url, transport);
 
         #[cfg(feature = "sync_mode")]
-        return ApiKey::check_api_key_validity_sync(reference_url);
+        return ApiKey::check_api_key_validity_sync(reference_url, transport);
     }
 
     fn get(&self) -> &str {
         &self.0
     }
 
+    /// creates an api key without validating it over the internet connection.
+    #[cfg(test)]
+    pub(crate) fn unchecked(key: &str) -> ApiKey {
+        ApiKey(key.to_string())
+    }
+
     /// is needed to automatically check validation of api key for new instance.
     /// 
     /// The internet connection is required to achieve the task.
@@ -126,9 +161,40 @@ impl<'a> ApiKey {
     ///     };
     /// ```
     pub fn from(key: String) -> Result<ApiKey, ReturnError> {
+        ApiKey::from_with_config(key, &ClientConfig::new())
+    }
+
+    /// is the same as [`ApiKey::from`](fn@ApiKey::from) except that the validation request is made with given
+    /// [`ClientConfig`](struct@ClientConfig), such as through a proxy.
+    ///
+    /// # Error
+    ///
+    /// The function will return error if given api key is invalid or there is a bad internet connection.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    ///     use tcmb_evds::common::{ApiKey, ClientConfig};
+    ///
+    ///
+    ///     let mut client_config = ClientConfig::new();
+    ///     client_config.change_proxy(Some("http://proxy.example.com:8080"));
+    ///
+    ///     let api_key = match ApiKey::from_with_config("users_key".to_string(), &client_config) {
+    ///         Err(return_error) => {
+    ///             println!("{}", return_error.to_string());
+    ///             return;
+    ///         },
+    ///         Ok(api_key) => api_key,
+    ///     };
+    /// ```
+    pub fn from_with_config(key: String, client_config: &ClientConfig) -> Result<ApiKey, ReturnError> {
         let api_key = ApiKey(key);
 
-        api_key.is_api_key_valid()?;
+        let mut transport = Transport::new();
+        transport.client_config = client_config.clone();
+
+        api_key.is_api_key_valid(&transport)?;
 
         Ok(api_key)
     }
@@ -149,10 +215,20 @@ impl traits::MakingUrlFormat for ApiKey {
 
 /// is composed of created [`ApiKey`](struct@ApiKey) and [`ReturnFormat`](crate::common::ReturnFormat) variables.
 ///
-/// This struct is common for each function that this crate provides.
+/// This struct is common for each function that this crate provides. It also carries the
+/// [`ClientConfig`](struct@ClientConfig) and [`RetryPolicy`](struct@RetryPolicy) that are applied to each request made
+/// with it. Connections opened by the requests are kept alive and reused by the following requests of the same Evds.
+///
+/// Clones of an Evds share their connection pool and [`RateLimiter`](struct@RateLimiter), so an Evds can be cloned
+/// into parallel workers. An optional [`MemoryCache`](struct@MemoryCache) and
+/// [`ResponseCache`](struct@ResponseCache) serve repeated requests from memory and disk respectively.
+#[derive(Clone)]
 pub struct Evds {
     api_key: ApiKey,
     return_format: ReturnFormat,
+    transport: Transport,
+    response_cache: Option<ResponseCache>,
+    memory_cache: Option<MemoryCache>,
 }
 
 impl<'a> Evds {
@@ -188,12 +264,17 @@ impl<'a> Evds {
         Evds {
             api_key,
             return_format,
+            transport: Transport::new(),
+            response_cache: None,
+            memory_cache: None,
         }
     }
 
     /// changes api key contained in Evds object if and only if the given key is valid.
     ///
-    /// The internet connection is required to achieve the task.
+    /// The internet connection is required to achieve the task. The key is checked with the
+    /// [`ClientConfig`](struct@ClientConfig), [`RetryPolicy`](struct@RetryPolicy) and
+    /// [`RateLimiter`](struct@RateLimiter) of the Evds.
     ///
     /// # Error
     /// 
@@ -219,7 +300,7 @@ impl<'a> Evds {
     /// ```
     pub fn change_api_key(&mut self, api_key: &str) -> Result<(), ReturnError> {
 
-        self.api_key.change(api_key)?;
+        self.api_key.change(api_key, &self.transport)?;
 
         Ok(())
     }
@@ -250,11 +331,233 @@ impl<'a> Evds {
         self.return_format = return_format;
     }
 
+    /// changes client configuration inside of an [`Evds`](struct@Evds) variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use tcmb_evds::common::{ApiKey, ReturnFormat, ClientConfig};
+    ///     use tcmb_evds::common::Evds;
+    /// # let result = ApiKey::from("users_key".to_string());
+    /// #
+    /// # if let Err(_) = result {
+    /// #   return;
+    /// # }
+    /// #
+    /// # let api_key = result.unwrap();
+    /// #
+    /// #
+    /// # let mut evds = Evds::from(api_key, ReturnFormat::Json);
+    ///
+    ///
+    ///     let mut client_config = ClientConfig::new();
+    ///     client_config.change_total_timeout(Some(Duration::from_secs(30)));
+    ///
+    ///     evds.change_client_config(client_config);
+    /// ```
+    pub fn change_client_config(&mut self, client_config: ClientConfig) {
+        self.transport.client_config = client_config;
+        self.transport.connection_pool.clear();
+    }
+
+    /// changes retry policy inside of an [`Evds`](struct@Evds) variable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::time::Duration;
+    /// # use tcmb_evds::common::{ApiKey, ReturnFormat, RetryPolicy};
+    ///     use tcmb_evds::common::Evds;
+    /// # let result = ApiKey::from("users_key".to_string());
+    /// #
+    /// # if let Err(_) = result {
+    /// #   return;
+    /// # }
+    /// #
+    /// # let api_key = result.unwrap();
+    /// #
+    /// #
+    /// # let mut evds = Evds::from(api_key, ReturnFormat::Json);
+    ///
+    ///
+    ///     let retry_policy = RetryPolicy::from(5, Duration::from_millis(500));
+    ///
+    ///     evds.change_retry_policy(retry_policy);
+    /// ```
+    pub fn change_retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.transport.retry_policy = retry_policy;
+    }
+
+    /// changes rate limiter inside of an [`Evds`](struct@Evds) variable. `None` removes the limit.
+    ///
+    /// Each request attempt made with the Evds or its clones passes through the given limiter.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcmb_evds::common::{ApiKey, ReturnFormat, RateLimiter};
+    ///     use tcmb_evds::common::Evds;
+    /// # let result = ApiKey::from("users_key".to_string());
+    /// #
+    /// # if let Err(_) = result {
+    /// #   return;
+    /// # }
+    /// #
+    /// # let api_key = result.unwrap();
+    /// #
+    /// #
+    /// # let mut evds = Evds::from(api_key, ReturnFormat::Json);
+    ///
+    ///
+    ///     let rate_limiter = RateLimiter::from(2.0, 5).unwrap();
+    ///
+    ///     evds.change_rate_limiter(Some(rate_limiter));
+    ///
+    ///     // Workers use clones sharing the same limiter.
+    ///     let worker_evds = evds.clone();
+    /// ```
+    pub fn change_rate_limiter(&mut self, rate_limiter: Option<RateLimiter>) {
+        self.transport.rate_limiter = rate_limiter;
+    }
+
+    /// gives the number of performed requests and newly established connections.
+    ///
+    /// The statistics make it possible to measure how many connections are saved by reusing kept alive connections.
+    /// Comparing elapsed times of a job with default [`ClientConfig`](struct@ClientConfig) and with zero idle
+    /// connections gives the speed-up.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcmb_evds::common::{ApiKey, ReturnFormat};
+    ///     use tcmb_evds::common::Evds;
+    /// # let result = ApiKey::from("users_key".to_string());
+    /// #
+    /// # if let Err(_) = result {
+    /// #   return;
+    /// # }
+    /// #
+    /// # let api_key = result.unwrap();
+    /// #
+    /// # let evds = Evds::from(api_key, ReturnFormat::Json);
+    ///
+    ///
+    ///     let stats = evds.connection_stats();
+    ///
+    ///     println!("{} requests made over {} connections", stats.requests, stats.new_connections);
+    /// ```
+    pub fn connection_stats(&self) -> ConnectionStats {
+        self.transport.connection_pool.stats()
+    }
+
+    /// changes response cache inside of an [`Evds`](struct@Evds) variable. `None` disables caching.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::error::Error;
+    /// # use tcmb_evds::common::{ApiKey, ReturnFormat, ResponseCache};
+    ///     use tcmb_evds::common::Evds;
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// # let result = ApiKey::from("users_key".to_string());
+    /// #
+    /// # if let Err(_) = result {
+    /// #   return Ok(());
+    /// # }
+    /// #
+    /// # let api_key = result.unwrap();
+    /// #
+    /// #
+    /// # let mut evds = Evds::from(api_key, ReturnFormat::Json);
+    ///
+    ///
+    ///     let response_cache = ResponseCache::from(std::env::temp_dir().join("evds_cache"))?;
+    ///
+    ///     evds.change_response_cache(Some(response_cache));
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn change_response_cache(&mut self, response_cache: Option<ResponseCache>) {
+        self.response_cache = response_cache;
+    }
+
+    /// changes memory cache inside of an [`Evds`](struct@Evds) variable. `None` disables caching in memory.
+    ///
+    /// The memory cache is looked up before the [`ResponseCache`](struct@ResponseCache).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use tcmb_evds::common::{ApiKey, ReturnFormat, MemoryCache};
+    ///     use tcmb_evds::common::Evds;
+    /// # let result = ApiKey::from("users_key".to_string());
+    /// #
+    /// # if let Err(_) = result {
+    /// #   return;
+    /// # }
+    /// #
+    /// # let api_key = result.unwrap();
+    /// #
+    /// #
+    /// # let mut evds = Evds::from(api_key, ReturnFormat::Json);
+    ///
+    ///
+    ///     let memory_cache = MemoryCache::from(1000);
+    ///
+    ///     evds.change_memory_cache(Some(memory_cache.clone()));
+    ///
+    ///     // ... requests ...
+    ///
+    ///     println!("{:?}", memory_cache.stats());
+    /// ```
+    pub fn change_memory_cache(&mut self, memory_cache: Option<MemoryCache>) {
+        self.memory_cache = memory_cache;
+    }
+
+    /// composes a request of the given urls without sending it, with the api key redacted.
+    pub(crate) fn compose_request(&self, urls: Vec<String>) -> Request {
+        let api_key_as_url = self.api_key.generate_url_format();
+        let redacted_key = format!("key={}", request::REDACTED_KEY);
+
+        Request {
+            urls: urls.iter().map(|url| url.replace(&api_key_as_url, &redacted_key)).collect(),
+            headers: vec![("User-Agent".to_string(), self.transport.client_config.get_user_agent().to_string())],
+        }
+    }
+
+    /// gives the cached response of the url from the memory cache and then the response cache if there are, otherwise
+    /// makes the request.
+    pub(crate) fn cached<F>(&self, url: &str, request: F) -> Result<String, ReturnError>
+    where
+        F: FnOnce() -> Result<String, ReturnError>,
+    {
+        let from_disk = || match &self.response_cache {
+            Some(response_cache) => response_cache.fetch(url, request),
+            None => request(),
+        };
+
+        match &self.memory_cache {
+            Some(memory_cache) => memory_cache.fetch(url, from_disk),
+            None => from_disk(),
+        }
+    }
+
+    /// gives the transport settings and shared state used for the requests.
+    pub(crate) fn get_transport(&self) -> &Transport {
+        &self.transport
+    }
+
     /// generates url format of api key.
     pub(crate) fn get_api_key_as_url(&self) -> String {
         self.api_key.generate_url_format()
     }
 
+    /// gives return format of the responses.
+    pub(crate) fn get_return_format(&self) -> &ReturnFormat {
+        &self.return_format
+    }
+
     /// generates url format of return format.
     pub(crate) fn get_return_format_as_url(&self) -> String {
         self.return_format.generate_url_format()
@@ -276,7 +579,7 @@ mod tests {
             },
         };
 
-        if let Err(message) = api_key.change("new_key") {
+        if let Err(message) = api_key.change("new_key", &Transport::new()) {
             println!("{}", message.to_string());
         };
     }
@@ -298,6 +601,26 @@ mod tests {
         }
     }
 
+    #[test]
+    fn should_validate_api_key_with_client_config() {
+        use crate::stub_server::StubServer;
+
+        let server = StubServer::start(vec![(407, "")]);
+
+        let mut client_config = ClientConfig::new();
+        client_config.change_proxy(Some(&server.url()));
+
+        let mut evds = Evds::from(ApiKey::unchecked("abc"), ReturnFormat::Json);
+        evds.change_client_config(client_config.clone());
+        evds.change_retry_policy(RetryPolicy::no_retry());
+
+        assert!(evds.change_api_key("new_key").is_err());
+        assert_eq!(Some("evds2.tcmb.gov.tr:443"), server.requested_paths().first().map(String::as_str));
+
+        assert!(ApiKey::from_with_config("new_key".to_string(), &client_config).is_err());
+        assert!(server.request_count() > 1);
+    }
+
     #[test]
     fn evds_functionalities_should_work() {
         let api_key = match ApiKey::from("abc".to_string()) {
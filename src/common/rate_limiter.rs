@@ -0,0 +1,154 @@
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use crate::error::ReturnError;
+
+
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+
+/// limits the number of requests with a token bucket shared by all of its clones.
+///
+/// The bucket holds up to `burst` tokens and is refilled with `requests_per_second` tokens each second. Each request
+/// attempt takes a token and waits when the bucket is empty. Cloning the limiter, or an
+/// [`Evds`](struct@crate::common::Evds) using it, shares the same bucket, so that parallel workers using one api key
+/// stay under the limit together.
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::common::RateLimiter;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///
+///     // 5 requests per second with bursts of up to 10 requests.
+///     let rate_limiter = RateLimiter::from(5.0, 10)?;
+///
+///     // The clone shares the same bucket.
+///     let shared_rate_limiter = rate_limiter.clone();
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RateLimiter {
+    requests_per_second: f64,
+    burst: u32,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+impl RateLimiter {
+    /// creates a rate limiter whose bucket is initially full.
+    ///
+    /// Zero burst is treated as 1.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the given rate is not a positive finite number.
+    pub fn from(requests_per_second: f64, burst: u32) -> Result<RateLimiter, ReturnError> {
+        if !requests_per_second.is_finite() || requests_per_second <= 0.0 {
+            return Err(ReturnError::InvalidRateLimit);
+        }
+
+        let burst = burst.max(1);
+
+        Ok(RateLimiter {
+            requests_per_second,
+            burst,
+            bucket: Arc::new(Mutex::new(Bucket { tokens: burst as f64, last_refill: Instant::now() })),
+        })
+    }
+
+    /// gives the number of tokens added to the bucket each second.
+    pub fn requests_per_second(&self) -> f64 {
+        self.requests_per_second
+    }
+
+    /// gives the capacity of the bucket.
+    pub fn burst(&self) -> u32 {
+        self.burst
+    }
+
+    /// takes a token and blocks the current thread until the token is available.
+    ///
+    /// The token is reserved before waiting, therefore waiting threads are served in the order they arrive.
+    pub(crate) fn acquire(&self) {
+        let wait = {
+            let mut bucket = match self.bucket.lock() {
+                Ok(bucket) => bucket,
+                Err(poisoned) => poisoned.into_inner(),
+            };
+
+            let now = Instant::now();
+            let refill = now.duration_since(bucket.last_refill).as_secs_f64() * self.requests_per_second;
+
+            bucket.tokens = (bucket.tokens + refill).min(self.burst as f64);
+            bucket.last_refill = now;
+            bucket.tokens -= 1.0;
+
+            if bucket.tokens >= 0.0 { return }
+
+            Duration::from_secs_f64(-bucket.tokens / self.requests_per_second)
+        };
+
+        thread::sleep(wait);
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_reject_invalid_rates() {
+        assert!(RateLimiter::from(0.0, 1).is_err());
+        assert!(RateLimiter::from(-1.0, 1).is_err());
+        assert!(RateLimiter::from(f64::NAN, 1).is_err());
+        assert_eq!(1, RateLimiter::from(1.0, 0).unwrap().burst());
+    }
+
+    #[test]
+    fn should_allow_burst_then_throttle() {
+        let rate_limiter = RateLimiter::from(20.0, 2).unwrap();
+        let started = Instant::now();
+
+        rate_limiter.acquire();
+        rate_limiter.acquire();
+
+        assert!(started.elapsed() < Duration::from_millis(40));
+
+        for _ in 0..4 {
+            rate_limiter.acquire();
+        }
+
+        assert!(started.elapsed() >= Duration::from_millis(180));
+    }
+
+    #[test]
+    fn should_share_bucket_across_clones_and_threads() {
+        let rate_limiter = RateLimiter::from(50.0, 1).unwrap();
+        let started = Instant::now();
+
+        let workers: Vec<_> = (0..4).map(|_| {
+            let rate_limiter = rate_limiter.clone();
+
+            thread::spawn(move || {
+                for _ in 0..3 {
+                    rate_limiter.acquire();
+                }
+            })
+        }).collect();
+
+        for worker in workers {
+            worker.join().unwrap();
+        }
+
+        // 12 tokens with 1 initial token require at least 11 / 50 seconds.
+        assert!(started.elapsed() >= Duration::from_millis(200));
+    }
+}
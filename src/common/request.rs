@@ -0,0 +1,47 @@
+use std::fmt;
+
+
+/// replaces the api key in the urls of a [`Request`](struct@Request).
+pub(crate) const REDACTED_KEY: &str = "REDACTED";
+
+
+/// is a request composed by an operation of [`tcmb_evds`](crate) without being sent.
+///
+/// An operation may be sent as several GET requests, such as data series exceeding the url limits of
+/// [`ClientConfig`](struct@crate::common::ClientConfig), therefore each of their urls is kept in order. The api key in
+/// the urls is replaced by "REDACTED", so that requests can be logged and shared safely.
+///
+/// Requests are given by [`evds_basic::build_request`](fn@crate::evds_basic::build_request),
+/// [`SeriesQuery::build_request`](fn@crate::evds_basic::SeriesQuery::build_request) and the `build_request` functions
+/// of [`CurrencySeries`](struct@crate::evds_currency::CurrencySeries) and
+/// [`MultipleCurrencySeries`](struct@crate::evds_currency::MultipleCurrencySeries), which compose the urls from the
+/// options without any I/O.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Request {
+    /// urls of the GET requests in the order they would be sent.
+    pub urls: Vec<String>,
+    /// headers sent with each request as name and value pairs.
+    pub headers: Vec<(String, String)>,
+}
+
+impl Request {
+    /// gives the url of the request. Urls of a request sent in several parts are separated by new lines.
+    pub fn to_url(&self) -> String {
+        self.urls.join("\n")
+    }
+}
+
+impl fmt::Display for Request {
+    /// writes each url after "GET" and the headers below them like an HTTP request.
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        for url in &self.urls {
+            writeln!(formatter, "GET {}", url)?;
+        }
+
+        for (name, value) in &self.headers {
+            writeln!(formatter, "{}: {}", name, value)?;
+        }
+
+        Ok(())
+    }
+}
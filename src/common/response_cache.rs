@@ -0,0 +1,359 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::date::calendar::{self, Day};
+use crate::error::ReturnError;
+
+
+/// counts the temporary files written by this process, so that each of them has a distinct name.
+static TEMPORARY_FILES: AtomicU64 = AtomicU64::new(0);
+
+/// provides working modes of a [`ResponseCache`](struct@ResponseCache).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CacheMode {
+    /// uses fresh cached responses and requests the rest, which are cached afterwards.
+    Normal,
+    /// uses cached responses regardless of their age and never makes a request. Missing responses end up with error.
+    CacheOnly,
+    /// always requests and replaces cached responses.
+    Refresh,
+}
+
+
+/// provides kinds of requested data whose cached responses expire at different times.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum DataKind {
+    /// categories, data groups and series lists.
+    Catalog,
+    /// observations of a date range that ended before today.
+    Historical,
+    /// observations of a date range including today or future.
+    Recent,
+}
+
+impl DataKind {
+    /// classifies the request by the url.
+    pub(crate) fn of_url(url: &str, today: Day) -> DataKind {
+        if url.contains("/categories/") || url.contains("/datagroups/") || url.contains("/serieList/") {
+            return DataKind::Catalog;
+        }
+
+        let end_date = url.split('&')
+            .find_map(|parameter| parameter.strip_prefix("endDate="))
+            .and_then(Day::parse);
+
+        match end_date {
+            Some(end_date) if end_date < today => DataKind::Historical,
+            _ => DataKind::Recent,
+        }
+    }
+}
+
+
+/// stores responses on disk to be used instead of repeating the same requests.
+///
+/// Responses are keyed by the normalised request, which is the url without the api key and with sorted parameters.
+/// Therefore, the same query made with different api keys uses the same cached response.
+///
+/// Each kind of data has its own time to live:
+///
+/// - *catalog* is used for categories, data groups and series lists. Default is 1 day.
+/// - *historical* is used for date ranges ended before today in Istanbul time. Default is 30 days.
+/// - *recent* is used for date ranges including today. Default is 15 minutes.
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+///     use std::time::Duration;
+///     use tcmb_evds::common::{CacheMode, ResponseCache};
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// #   let directory = std::env::temp_dir().join("tcmb_evds_doc_cache");
+///     let mut response_cache = ResponseCache::from(&directory)?;
+///
+///     response_cache.change_historical_ttl(Duration::from_secs(365 * 24 * 60 * 60));
+///
+///     // offline analysis with cached responses only.
+///     response_cache.change_mode(CacheMode::CacheOnly);
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct ResponseCache {
+    directory: PathBuf,
+    catalog_ttl: Duration,
+    historical_ttl: Duration,
+    recent_ttl: Duration,
+    mode: CacheMode,
+}
+
+impl ResponseCache {
+    /// creates a cache storing responses in the given directory which is created if it does not exist.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the directory cannot be created.
+    pub fn from<P: AsRef<Path>>(directory: P) -> Result<ResponseCache, ReturnError> {
+        let directory = directory.as_ref().to_path_buf();
+
+        if fs::create_dir_all(&directory).is_err() {
+            return Err(ReturnError::UnableToAccessCache);
+        }
+
+        Ok(ResponseCache {
+            directory,
+            catalog_ttl: Duration::from_secs(24 * 60 * 60),
+            historical_ttl: Duration::from_secs(30 * 24 * 60 * 60),
+            recent_ttl: Duration::from_secs(15 * 60),
+            mode: CacheMode::Normal,
+        })
+    }
+
+    /// changes time to live of categories, data groups and series lists.
+    pub fn change_catalog_ttl(&mut self, catalog_ttl: Duration) {
+        self.catalog_ttl = catalog_ttl;
+    }
+
+    /// changes time to live of date ranges ended before today.
+    pub fn change_historical_ttl(&mut self, historical_ttl: Duration) {
+        self.historical_ttl = historical_ttl;
+    }
+
+    /// changes time to live of date ranges including today.
+    pub fn change_recent_ttl(&mut self, recent_ttl: Duration) {
+        self.recent_ttl = recent_ttl;
+    }
+
+    /// changes working mode.
+    pub fn change_mode(&mut self, mode: CacheMode) {
+        self.mode = mode;
+    }
+
+    /// removes all cached responses.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the cached responses cannot be removed.
+    pub fn clear(&self) -> Result<(), ReturnError> {
+        let entries = fs::read_dir(&self.directory).map_err(|_| ReturnError::UnableToAccessCache)?;
+
+        for entry in entries.flatten() {
+            let path = entry.path();
+
+            if path.extension().is_some_and(|extension| extension == "cache") {
+                fs::remove_file(path).map_err(|_| ReturnError::UnableToAccessCache)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn ttl_of(&self, data_kind: DataKind) -> Duration {
+        match data_kind {
+            DataKind::Catalog => self.catalog_ttl,
+            DataKind::Historical => self.historical_ttl,
+            DataKind::Recent => self.recent_ttl,
+        }
+    }
+
+    fn path_of(&self, key: &str) -> PathBuf {
+        self.directory.join(format!("{:016x}.cache", fnv1a_hash(key)))
+    }
+
+    /// reads the cached response of the key if it is younger than given time to live.
+    ///
+    /// Each file contains the key, the storing time in seconds and the response in separate lines.
+    fn read(&self, key: &str, ttl: Option<Duration>) -> Option<String> {
+        let content = fs::read_to_string(self.path_of(key)).ok()?;
+
+        let mut parts = content.splitn(3, '\n');
+
+        if parts.next()? != key { return None }
+
+        let stored_at = parts.next()?.parse::<u64>().ok()?;
+        let response = parts.next()?;
+
+        if let Some(ttl) = ttl {
+            if seconds_now().saturating_sub(stored_at) >= ttl.as_secs() { return None }
+        }
+
+        Some(response.to_string())
+    }
+
+    /// writes the response via a temporary file, so that readers never see a partially written response.
+    fn write(&self, key: &str, response: &str) -> Result<(), ReturnError> {
+        let path = self.path_of(key);
+        let temporary_path = temporary_path_of(&path);
+
+        let content = format!("{}\n{}\n{}", key, seconds_now(), response);
+
+        fs::write(&temporary_path, content).map_err(|_| ReturnError::UnableToAccessCache)?;
+        fs::rename(&temporary_path, &path).map_err(|_| {
+            let _ = fs::remove_file(&temporary_path);
+            ReturnError::UnableToAccessCache
+        })
+    }
+
+    /// gives the cached response of the url or requests it with the given function according to the cache mode.
+    ///
+    /// Only successful responses are cached. A failure of writing the cache does not fail the request.
+    ///
+    /// # Error
+    ///
+    /// This function returns the error of the request or an error if the response is not cached in cache only mode.
+    pub(crate) fn fetch<F>(&self, url: &str, request: F) -> Result<String, ReturnError>
+    where
+        F: FnOnce() -> Result<String, ReturnError>,
+    {
+        let key = normalise_url(url);
+
+        match self.mode {
+            CacheMode::CacheOnly => return self.read(&key, None).ok_or(ReturnError::NotCached),
+            CacheMode::Normal => {
+                let ttl = self.ttl_of(DataKind::of_url(url, calendar::today_in_istanbul()));
+
+                if let Some(response) = self.read(&key, Some(ttl)) { return Ok(response) }
+            },
+            CacheMode::Refresh => {},
+        }
+
+        let response = request()?;
+
+        let _ = self.write(&key, &response);
+
+        Ok(response)
+    }
+}
+
+
+/// removes the api key from the url and sorts parameters except the first one containing the series or the path.
+pub(crate) fn normalise_url(url: &str) -> String {
+    let mut segments: Vec<&str> = url.split('&')
+        .map(|segment| match segment.find("key=") {
+            Some(index) if index == 0 || segment[..index].ends_with('/') => &segment[..index],
+            _ => segment,
+        })
+        .filter(|segment| !segment.is_empty())
+        .collect();
+
+    if segments.len() > 2 {
+        segments[1..].sort_unstable();
+    }
+
+    segments.join("&")
+}
+
+/// is the 64 bit FNV-1a hash which is stable between compilations unlike the default hasher.
+fn fnv1a_hash(text: &str) -> u64 {
+    text.bytes().fold(0xcbf2_9ce4_8422_2325, |hash, byte| (hash ^ byte as u64).wrapping_mul(0x0100_0000_01b3))
+}
+
+/// gives a temporary path next to the path, which is distinct for each process, thread and write.
+fn temporary_path_of(path: &Path) -> PathBuf {
+    let count = TEMPORARY_FILES.fetch_add(1, Ordering::Relaxed);
+
+    path.with_extension(format!("{}.{:?}.{}.tmp", std::process::id(), std::thread::current().id(), count))
+}
+
+fn seconds_now() -> u64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs(),
+        Err(_) => 0,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use super::*;
+
+    fn temporary_cache(name: &str) -> ResponseCache {
+        let directory = std::env::temp_dir().join(format!("tcmb_evds_cache_{}_{}", name, std::process::id()));
+        let response_cache = ResponseCache::from(&directory).unwrap();
+        response_cache.clear().unwrap();
+
+        response_cache
+    }
+
+    #[test]
+    fn should_normalise_without_api_key() {
+        let url = "https://evds2.tcmb.gov.tr/service/evds/series=TP.DK.USD.A&type=json&key=abc&startDate=01-01-2015";
+
+        assert_eq!(
+            "https://evds2.tcmb.gov.tr/service/evds/series=TP.DK.USD.A&startDate=01-01-2015&type=json",
+            normalise_url(url)
+        );
+        assert_eq!(
+            "https://evds2.tcmb.gov.tr/service/evds/categories/&type=xml",
+            normalise_url("https://evds2.tcmb.gov.tr/service/evds/categories/key=abc&type=xml")
+        );
+    }
+
+    #[test]
+    fn should_give_distinct_temporary_paths() {
+        let path = Path::new("cache").join("0123456789abcdef.cache");
+
+        let first = temporary_path_of(&path);
+        let second = temporary_path_of(&path);
+
+        assert_ne!(first, second);
+        assert!(first.to_string_lossy().contains(&std::process::id().to_string()));
+    }
+
+    #[test]
+    fn should_classify_data_kinds() {
+        let today = Day::parse("15-06-2021").unwrap();
+
+        assert_eq!(DataKind::Catalog, DataKind::of_url("https://evds2.tcmb.gov.tr/service/evds/serieList/key=a", today));
+        assert_eq!(DataKind::Historical, DataKind::of_url("series=A&startDate=01-01-2015&endDate=31-12-2015", today));
+        assert_eq!(DataKind::Recent, DataKind::of_url("series=A&startDate=01-01-2021&endDate=15-06-2021", today));
+    }
+
+    #[test]
+    fn should_use_cached_response_of_any_api_key() {
+        let response_cache = temporary_cache("normal");
+        let request_count = Cell::new(0);
+
+        let request = || {
+            request_count.set(request_count.get() + 1);
+            Ok("response\nwith lines".to_string())
+        };
+
+        let first = response_cache.fetch("series=A&endDate=01-01-2015&key=first", request);
+        let second = response_cache.fetch("series=A&endDate=01-01-2015&key=second", request);
+
+        assert_eq!(Ok("response\nwith lines".to_string()), first);
+        assert_eq!(first, second);
+        assert_eq!(1, request_count.get());
+    }
+
+    #[test]
+    fn should_follow_cache_modes_and_ttl() {
+        let mut response_cache = temporary_cache("modes");
+        let url = "series=A&endDate=01-01-2015&key=abc";
+
+        response_cache.change_mode(CacheMode::CacheOnly);
+        assert_eq!(Err(ReturnError::NotCached), response_cache.fetch(url, || Ok("network".to_string())));
+
+        response_cache.change_mode(CacheMode::Refresh);
+        assert_eq!(Ok("first".to_string()), response_cache.fetch(url, || Ok("first".to_string())));
+        assert_eq!(Ok("second".to_string()), response_cache.fetch(url, || Ok("second".to_string())));
+
+        response_cache.change_mode(CacheMode::Normal);
+        response_cache.change_historical_ttl(Duration::from_secs(0));
+        assert_eq!(Ok("third".to_string()), response_cache.fetch(url, || Ok("third".to_string())));
+
+        response_cache.change_mode(CacheMode::CacheOnly);
+        assert_eq!(Ok("third".to_string()), response_cache.fetch(url, || Ok("network".to_string())));
+
+        response_cache.change_mode(CacheMode::Normal);
+        response_cache.change_historical_ttl(Duration::from_secs(60));
+        assert_eq!(Err(ReturnError::EmptyResponse), response_cache.fetch("series=B", || Err(ReturnError::EmptyResponse)));
+        assert_eq!(Ok("third".to_string()), response_cache.fetch(url, || Ok("network".to_string())));
+    }
+}
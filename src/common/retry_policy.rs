@@ -0,0 +1,277 @@
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+
+/// the biggest power of two that the base delay is multiplied by while backing off.
+const MAX_BACKOFF_EXPONENT: u32 = 16;
+
+
+/// provides the error classes that a [`RetryPolicy`](struct@RetryPolicy) is able to retry.
+///
+/// Errors that do not fall into one of these classes, such as an invalid url or a client side HTTP error, can never
+/// succeed by repeating the same request. Therefore, they are not retried at all.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ErrorClass {
+    /// the host could not be resolved, the connection could not be established or it was broken during transfer.
+    Connection,
+    /// the operation did not finish in time.
+    Timeout,
+    /// the server answered with a 5xx status code.
+    ServerError,
+    /// the server answered with 429 status code.
+    TooManyRequests,
+}
+
+impl ErrorClass {
+    /// classifies a failed `perform()` call of a curl handle.
+    pub(crate) fn from_curl_error(error: &curl::Error) -> Option<ErrorClass> {
+        if error.is_operation_timedout() { return Some(ErrorClass::Timeout) }
+
+        if error.is_couldnt_resolve_host()
+            || error.is_couldnt_resolve_proxy()
+            || error.is_couldnt_connect()
+            || error.is_send_error()
+            || error.is_recv_error()
+            || error.is_got_nothing()
+            || error.is_partial_file() {
+            return Some(ErrorClass::Connection)
+        }
+
+        None
+    }
+
+    /// classifies the HTTP status code of a completed transfer.
+    pub(crate) fn from_response_code(code: u32) -> Option<ErrorClass> {
+        match code {
+            429 => Some(ErrorClass::TooManyRequests),
+            500..=599 => Some(ErrorClass::ServerError),
+            _ => None,
+        }
+    }
+}
+
+
+/// configures how many times and how patiently a failed request is repeated.
+///
+/// The delay before the n-th repetition is `base_delay * 2^(n - 1)`. Jitter randomly shortens each delay by up to the
+/// given ratio so that clients sharing the same policy do not retry at the same time. An optional deadline bounds the
+/// overall time spent including all attempts and delays.
+///
+/// Default policy makes 3 attempts, starts with 200 milliseconds of delay, uses 0.5 jitter ratio, has no deadline and
+/// retries all of the [`ErrorClass`](enum@ErrorClass) options.
+///
+/// # Example
+///
+/// ```
+///     use std::time::Duration;
+///     use tcmb_evds::common::{ErrorClass, RetryPolicy};
+///
+///
+///     let mut retry_policy = RetryPolicy::from(5, Duration::from_millis(500));
+///
+///     retry_policy.change_jitter(0.25);
+///     retry_policy.change_deadline(Some(Duration::from_secs(30)));
+///     retry_policy.exclude_error_class(ErrorClass::TooManyRequests);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    jitter: f64,
+    retryable_errors: Vec<ErrorClass>,
+    deadline: Option<Duration>,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            jitter: 0.5,
+            retryable_errors: vec![
+                ErrorClass::Connection,
+                ErrorClass::Timeout,
+                ErrorClass::ServerError,
+                ErrorClass::TooManyRequests,
+            ],
+            deadline: None,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// creates the default retry policy.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy::default()
+    }
+
+    /// creates a retry policy with given number of attempts and base delay and default values for the rest.
+    ///
+    /// Zero attempts are treated as a single attempt.
+    pub fn from(max_attempts: u32, base_delay: Duration) -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: max_attempts.max(1),
+            base_delay,
+            ..Default::default()
+        }
+    }
+
+    /// creates a policy making only one attempt.
+    pub fn no_retry() -> RetryPolicy {
+        RetryPolicy::from(1, Duration::from_millis(0))
+    }
+
+    /// changes maximum number of attempts including the first one.
+    pub fn change_max_attempts(&mut self, max_attempts: u32) {
+        self.max_attempts = max_attempts.max(1);
+    }
+
+    /// changes the delay used before the first repetition.
+    pub fn change_base_delay(&mut self, base_delay: Duration) {
+        self.base_delay = base_delay;
+    }
+
+    /// changes jitter ratio which is clamped between 0.0 and 1.0.
+    pub fn change_jitter(&mut self, jitter: f64) {
+        self.jitter = if jitter.is_nan() { 0.0 } else { jitter.clamp(0.0, 1.0) };
+    }
+
+    /// changes overall deadline. `None` removes the deadline.
+    pub fn change_deadline(&mut self, deadline: Option<Duration>) {
+        self.deadline = deadline;
+    }
+
+    /// makes given error class retryable.
+    pub fn include_error_class(&mut self, error_class: ErrorClass) {
+        if !self.retryable_errors.contains(&error_class) {
+            self.retryable_errors.push(error_class);
+        }
+    }
+
+    /// makes given error class fail immediately.
+    pub fn exclude_error_class(&mut self, error_class: ErrorClass) {
+        self.retryable_errors.retain(|element| *element != error_class);
+    }
+
+    /// gives maximum number of attempts.
+    pub fn max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// checks whether given error class is retryable or not.
+    pub fn is_retryable(&self, error_class: ErrorClass) -> bool {
+        self.retryable_errors.contains(&error_class)
+    }
+
+    /// calculates the delay before the repetition following given attempt number without jitter.
+    ///
+    /// Attempt numbers start from 1.
+    pub(crate) fn backoff_delay(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(MAX_BACKOFF_EXPONENT);
+
+        self.base_delay.saturating_mul(1 << exponent)
+    }
+
+    /// decides whether the failed attempt is repeated and sleeps during the backoff delay if it is.
+    ///
+    /// `started` is the moment the first attempt was made and it is used to respect the deadline. The function returns
+    /// false without sleeping when the error is not retryable, attempts are exhausted or the deadline would be passed.
+    pub(crate) fn wait_for_next_attempt(
+        &self,
+        attempt: u32,
+        started: Instant,
+        error_class: Option<ErrorClass>
+    ) -> bool {
+        let error_class = match error_class {
+            Some(error_class) => error_class,
+            None => return false,
+        };
+
+        if !self.is_retryable(error_class) || attempt >= self.max_attempts { return false }
+
+        let delay = self.backoff_delay(attempt).mul_f64(1.0 - self.jitter * random_ratio());
+
+        if let Some(deadline) = self.deadline {
+            if started.elapsed() + delay >= deadline { return false }
+        }
+
+        thread::sleep(delay);
+
+        true
+    }
+}
+
+/// generates a pseudo random number in [0, 1) that is good enough to spread retries of different clients.
+fn random_ratio() -> f64 {
+    let nanos = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.subsec_nanos() as u64,
+        Err(_) => 0,
+    };
+
+    // xorshift64* step over the current nanoseconds and the thread id based seed.
+    let mut state = nanos ^ 0x9E37_79B9_7F4A_7C15 ^ (thread_seed() << 17);
+    state ^= state >> 12;
+    state ^= state << 25;
+    state ^= state >> 27;
+    let value = state.wrapping_mul(0x2545_F491_4F6C_DD1D);
+
+    (value >> 11) as f64 / (1u64 << 53) as f64
+}
+
+fn thread_seed() -> u64 {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    thread::current().id().hash(&mut hasher);
+    hasher.finish()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_back_off_exponentially() {
+        let retry_policy = RetryPolicy::from(5, Duration::from_millis(100));
+
+        assert_eq!(Duration::from_millis(100), retry_policy.backoff_delay(1));
+        assert_eq!(Duration::from_millis(200), retry_policy.backoff_delay(2));
+        assert_eq!(Duration::from_millis(800), retry_policy.backoff_delay(4));
+        assert_eq!(retry_policy.backoff_delay(100), retry_policy.backoff_delay(MAX_BACKOFF_EXPONENT + 1));
+    }
+
+    #[test]
+    fn should_stop_when_not_retryable_or_exhausted() {
+        let mut retry_policy = RetryPolicy::from(2, Duration::from_millis(0));
+        retry_policy.exclude_error_class(ErrorClass::ServerError);
+
+        let started = Instant::now();
+
+        assert!(!retry_policy.wait_for_next_attempt(1, started, None));
+        assert!(!retry_policy.wait_for_next_attempt(1, started, Some(ErrorClass::ServerError)));
+        assert!(retry_policy.wait_for_next_attempt(1, started, Some(ErrorClass::Connection)));
+        assert!(!retry_policy.wait_for_next_attempt(2, started, Some(ErrorClass::Connection)));
+    }
+
+    #[test]
+    fn should_respect_deadline() {
+        let mut retry_policy = RetryPolicy::from(10, Duration::from_secs(5));
+        retry_policy.change_jitter(0.0);
+        retry_policy.change_deadline(Some(Duration::from_secs(1)));
+
+        assert!(!retry_policy.wait_for_next_attempt(1, Instant::now(), Some(ErrorClass::Timeout)));
+    }
+
+    #[test]
+    fn should_classify_response_codes() {
+        assert_eq!(Some(ErrorClass::TooManyRequests), ErrorClass::from_response_code(429));
+        assert_eq!(Some(ErrorClass::ServerError), ErrorClass::from_response_code(503));
+        assert_eq!(None, ErrorClass::from_response_code(404));
+        assert_eq!(None, ErrorClass::from_response_code(200));
+
+        let jittered = (0..100).map(|_| random_ratio()).all(|ratio| (0.0..1.0).contains(&ratio));
+        assert!(jittered);
+    }
+}
@@ -0,0 +1,34 @@
+use std::sync::Arc;
+
+use super::{ClientConfig, ConnectionPool, RateLimiter, RetryPolicy};
+
+
+/// bundles the settings and the shared state that the transport layer uses for each request.
+///
+/// Cloning a transport shares its connection pool and rate limiter.
+#[derive(Clone)]
+pub(crate) struct Transport {
+    pub(crate) client_config: ClientConfig,
+    pub(crate) retry_policy: RetryPolicy,
+    pub(crate) connection_pool: Arc<ConnectionPool>,
+    pub(crate) rate_limiter: Option<RateLimiter>,
+}
+
+impl Transport {
+    /// creates a transport with default settings, an empty connection pool and no rate limiter.
+    pub(crate) fn new() -> Transport {
+        Transport {
+            client_config: ClientConfig::new(),
+            retry_policy: RetryPolicy::new(),
+            connection_pool: Arc::new(ConnectionPool::new()),
+            rate_limiter: None,
+        }
+    }
+
+    /// waits for the rate limiter, if there is one, before an attempt is made.
+    pub(crate) fn wait_for_rate_limit(&self) {
+        if let Some(rate_limiter) = &self.rate_limiter {
+            rate_limiter.acquire();
+        }
+    }
+}
@@ -0,0 +1,440 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use crate::date::calendar::Day;
+use crate::date::DateRange;
+use crate::error::ReturnError;
+use crate::evds_currency::frequency_formulas::DataFrequency;
+use crate::response::Observations;
+
+
+/// are the official holidays of Turkey falling on the same date each year, given as day, month and the first year.
+const FIXED_HOLIDAYS: [(u32, u32, i32); 7] = [
+    (1, 1, 0),
+    (23, 4, 0),
+    (1, 5, 2009),
+    (19, 5, 0),
+    (15, 7, 2017),
+    (30, 8, 0),
+    (29, 10, 0),
+];
+
+/// are Ramazan Bayramı and Kurban Bayramı of Turkey from 2005 to 2026, given as the first day, month, year and the
+/// number of days. Half days on the eves of the holidays are business days.
+const RELIGIOUS_HOLIDAYS: [(u32, u32, i32, i64); 45] = [
+    (20, 1, 2005, 4), (3, 11, 2005, 3),
+    (10, 1, 2006, 4), (23, 10, 2006, 3), (31, 12, 2006, 4),
+    (12, 10, 2007, 3), (20, 12, 2007, 4),
+    (30, 9, 2008, 3), (8, 12, 2008, 4),
+    (20, 9, 2009, 3), (27, 11, 2009, 4),
+    (9, 9, 2010, 3), (16, 11, 2010, 4),
+    (30, 8, 2011, 3), (6, 11, 2011, 4),
+    (19, 8, 2012, 3), (25, 10, 2012, 4),
+    (8, 8, 2013, 3), (15, 10, 2013, 4),
+    (28, 7, 2014, 3), (4, 10, 2014, 4),
+    (17, 7, 2015, 3), (24, 9, 2015, 4),
+    (5, 7, 2016, 3), (12, 9, 2016, 4),
+    (25, 6, 2017, 3), (1, 9, 2017, 4),
+    (15, 6, 2018, 3), (21, 8, 2018, 4),
+    (4, 6, 2019, 3), (11, 8, 2019, 4),
+    (24, 5, 2020, 3), (31, 7, 2020, 4),
+    (13, 5, 2021, 3), (20, 7, 2021, 4),
+    (2, 5, 2022, 3), (9, 7, 2022, 4),
+    (21, 4, 2023, 3), (28, 6, 2023, 4),
+    (10, 4, 2024, 3), (16, 6, 2024, 4),
+    (30, 3, 2025, 3), (6, 6, 2025, 4),
+    (20, 3, 2026, 3), (27, 5, 2026, 4),
+];
+
+
+/// decides which days are business days for series with [`Business`](enum@DataFrequency) frequency.
+///
+/// Weekends are never business days. [`turkish`](fn@BusinessCalendar::turkish) calendar also excludes official
+/// holidays falling on the same date each year, and Ramazan Bayramı and Kurban Bayramı from 2005 to 2026. Religious
+/// holidays move every year, therefore holidays of later years and extended holidays need to be added with
+/// [`add_holiday`](fn@BusinessCalendar::add_holiday).
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::completeness::BusinessCalendar;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let mut business_calendar = BusinessCalendar::turkish();
+///
+///     assert!(!business_calendar.is_business_day("21-04-2023")?);
+///
+///     // Ramazan Bayramı of 2027.
+///     for date in ["09-03-2027", "10-03-2027", "11-03-2027"] {
+///         business_calendar.add_holiday(date)?;
+///     }
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BusinessCalendar {
+    fixed_holidays: bool,
+    holidays: BTreeSet<Day>,
+}
+
+impl BusinessCalendar {
+    /// creates a calendar whose business days are all weekdays.
+    pub fn new() -> BusinessCalendar {
+        BusinessCalendar { fixed_holidays: false, holidays: BTreeSet::new() }
+    }
+
+    /// creates a calendar excluding weekends, the fixed official holidays of Turkey and its religious holidays from
+    /// 2005 to 2026.
+    pub fn turkish() -> BusinessCalendar {
+        let holidays = RELIGIOUS_HOLIDAYS.iter()
+            .flat_map(|(day, month, year, length)| {
+                let first_day = Day { year: *year, month: *month, day: *day };
+
+                (0..*length).map(move |offset| first_day.add_days(offset))
+            })
+            .collect();
+
+        BusinessCalendar { fixed_holidays: true, holidays }
+    }
+
+    /// adds a holiday given in "day-month-year" format.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the date is invalid.
+    pub fn add_holiday(&mut self, date: &str) -> Result<(), ReturnError> {
+        self.holidays.insert(Day::parse(date).ok_or(ReturnError::InvalidDate)?);
+
+        Ok(())
+    }
+
+    /// checks the date given in "day-month-year" format is a business day.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the date is invalid.
+    pub fn is_business_day(&self, date: &str) -> Result<bool, ReturnError> {
+        Ok(self.is_business(Day::parse(date).ok_or(ReturnError::InvalidDate)?))
+    }
+
+    pub(crate) fn is_business(&self, day: Day) -> bool {
+        if day.is_weekend() || self.holidays.contains(&day) { return false }
+
+        let is_fixed_holiday = FIXED_HOLIDAYS.iter()
+            .any(|(holiday, month, since)| day.day == *holiday && day.month == *month && day.year >= *since);
+
+        !(self.fixed_holidays && is_fixed_holiday)
+    }
+}
+
+impl Default for BusinessCalendar {
+    fn default() -> BusinessCalendar {
+        BusinessCalendar::new()
+    }
+}
+
+
+/// is a run of consecutive expected periods whose observations have no value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct NullRun {
+    /// observation date of the first period in the run.
+    pub start_date: String,
+    /// observation date of the last period in the run.
+    pub end_date: String,
+    /// number of periods in the run.
+    pub length: usize,
+}
+
+
+/// lists the problems of a series compared with its declared frequency.
+///
+/// Periods are given with their first days in "day-month-year" format, e.g. "01-12-2011" for December 2011 of a
+/// monthly series and the Monday of the week for a weekly series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompletenessReport {
+    /// expected periods without an observation.
+    pub missing_periods: Vec<String>,
+    /// observation dates of the periods that have more than one observation, except the first one of each period.
+    pub duplicate_dates: Vec<String>,
+    /// runs of expected periods having only empty values.
+    pub null_runs: Vec<NullRun>,
+    gaps: Vec<(Day, Day)>,
+}
+
+impl CompletenessReport {
+    /// checks there is no missing period, duplicate date or null run.
+    pub fn is_complete(&self) -> bool {
+        self.missing_periods.is_empty() && self.duplicate_dates.is_empty() && self.null_runs.is_empty()
+    }
+
+    /// gives the smallest date ranges covering missing periods and null runs to request them again.
+    pub fn gap_ranges(&self) -> Vec<DateRange> {
+        self.gaps.iter()
+            .filter_map(|(start, end)| DateRange::from(&start.format(), &end.format()).ok())
+            .collect()
+    }
+}
+
+
+/// gives the first day of the period containing the day.
+fn period_start(day: Day, data_frequency: DataFrequency) -> Day {
+    let first_of = |month: u32| Day { year: day.year, month, day: 1 };
+
+    match data_frequency {
+        DataFrequency::Daily | DataFrequency::Business => day,
+        DataFrequency::WeeklyFriday => day.add_days(1 - day.weekday() as i64),
+        DataFrequency::TwiceMonthly => Day { day: if day.day < 16 { 1 } else { 16 }, ..day },
+        DataFrequency::Monthly => first_of(day.month),
+        DataFrequency::Quarterly => first_of((day.month - 1) / 3 * 3 + 1),
+        DataFrequency::SemiAnnual => first_of((day.month - 1) / 6 * 6 + 1),
+        DataFrequency::Annual => first_of(1),
+    }
+}
+
+/// gives the first day of the period following the period starting with the given day.
+fn next_period(start: Day, data_frequency: DataFrequency) -> Day {
+    let months_later = |months: u32| {
+        let month_index = start.month - 1 + months;
+
+        Day { year: start.year + (month_index / 12) as i32, month: month_index % 12 + 1, day: 1 }
+    };
+
+    match data_frequency {
+        DataFrequency::Daily | DataFrequency::Business => start.add_days(1),
+        DataFrequency::WeeklyFriday => start.add_days(7),
+        DataFrequency::TwiceMonthly if start.day == 1 => Day { day: 16, ..start },
+        DataFrequency::TwiceMonthly => months_later(1),
+        DataFrequency::Monthly => months_later(1),
+        DataFrequency::Quarterly => months_later(3),
+        DataFrequency::SemiAnnual => months_later(6),
+        DataFrequency::Annual => months_later(12),
+    }
+}
+
+/// checks the series of the observations over the date range against its declared frequency.
+///
+/// Observations of a [`Business`](enum@DataFrequency) series are expected on each business day of the calendar, and
+/// empty values on the other days, such as weekend rows of daily currency series, are ignored. Observations of the
+/// other frequencies are expected in each period, where the date of an observation can be any day of its period.
+///
+/// # Error
+///
+/// This function returns an error if the series does not exist in the observations, the dates of the range are
+/// invalid or an observation date cannot be read.
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::common::ReturnFormat;
+///     use tcmb_evds::completeness::{self, BusinessCalendar};
+///     use tcmb_evds::date::DateRange;
+///     use tcmb_evds::evds_currency::frequency_formulas::DataFrequency;
+///     use tcmb_evds::response::Observations;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let response = "Tarih,TP_FG_J0\n2021-1,1.68\n2021-3,1.08\n";
+///     let observations = Observations::parse(response, &ReturnFormat::Csv)?;
+///
+///     let date_range = DateRange::from("01-01-2021", "31-03-2021")?;
+///     let report = completeness::check(
+///         &observations,
+///         "TP.FG.J0",
+///         DataFrequency::Monthly,
+///         &date_range,
+///         &BusinessCalendar::turkish(),
+///     )?;
+///
+///     assert_eq!(vec!["01-02-2021".to_string()], report.missing_periods);
+/// #   Ok(())
+/// # }
+/// ```
+pub fn check(
+    observations: &Observations,
+    series: &str,
+    data_frequency: DataFrequency,
+    date_range: &DateRange,
+    business_calendar: &BusinessCalendar,
+) -> Result<CompletenessReport, ReturnError> {
+    let series_index = observations.series_index(series).ok_or(ReturnError::InvalidSeries)?;
+
+    let (start_date, end_date) = date_range.get_dates();
+    let start = Day::parse(start_date).ok_or(ReturnError::InvalidDate)?;
+    let end = Day::parse(end_date).ok_or(ReturnError::InvalidDate)?;
+
+    let is_expected = |period: Day| data_frequency != DataFrequency::Business || business_calendar.is_business(period);
+
+    let mut expected_periods = Vec::new();
+    let mut period = period_start(start, data_frequency);
+
+    while period <= end {
+        if is_expected(period) { expected_periods.push(period); }
+        period = next_period(period, data_frequency);
+    }
+
+    let first_period = period_start(start, data_frequency);
+    let mut observed: BTreeMap<Day, Vec<(&str, bool)>> = BTreeMap::new();
+
+    for row in &observations.rows {
+        let day = Day::parse_period(&row.date).ok_or(ReturnError::UnableToParseResponse)?;
+
+        if day < first_period || day > end { continue }
+
+        observed.entry(period_start(day, data_frequency))
+            .or_default()
+            .push((row.date.as_str(), row.values[series_index].is_some()));
+    }
+
+    let mut report = CompletenessReport {
+        missing_periods: Vec::new(),
+        duplicate_dates: Vec::new(),
+        null_runs: Vec::new(),
+        gaps: Vec::new(),
+    };
+
+    for (period, rows) in &observed {
+        if rows.len() > 1 && is_expected(*period) {
+            report.duplicate_dates.extend(rows[1..].iter().map(|(date, _)| date.to_string()));
+        }
+    }
+
+    let mut null_run: Option<NullRun> = None;
+    let mut gap: Option<(Day, Day)> = None;
+
+    for period in expected_periods {
+        let period_end = next_period(period, data_frequency).add_days(-1).min(end);
+
+        let is_gap = match observed.get(&period) {
+            None => {
+                report.missing_periods.push(period.format());
+                report.null_runs.extend(null_run.take());
+                true
+            },
+            Some(rows) if rows.iter().all(|(_, has_value)| !has_value) => {
+                let date = rows[0].0.to_string();
+
+                match &mut null_run {
+                    Some(run) => {
+                        run.end_date = date;
+                        run.length += 1;
+                    },
+                    None => null_run = Some(NullRun { start_date: date.clone(), end_date: date, length: 1 }),
+                }
+                true
+            },
+            Some(_) => {
+                report.null_runs.extend(null_run.take());
+                false
+            },
+        };
+
+        if is_gap {
+            gap = match gap {
+                Some((gap_start, _)) => Some((gap_start, period_end)),
+                None => Some((period.max(start), period_end)),
+            };
+        } else {
+            report.gaps.extend(gap.take());
+        }
+    }
+
+    report.null_runs.extend(null_run);
+    report.gaps.extend(gap);
+
+    Ok(report)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Observation;
+
+    fn single_series(rows: &[(&str, Option<f64>)]) -> Observations {
+        Observations {
+            series: vec!["TP_DK_USD_A".to_string()],
+            rows: rows.iter()
+                .map(|(date, value)| Observation { date: date.to_string(), values: vec![*value] })
+                .collect(),
+        }
+    }
+
+    fn dates_of(date_ranges: &[DateRange]) -> Vec<(&str, &str)> {
+        date_ranges.iter().map(|date_range| date_range.get_dates()).collect()
+    }
+
+    #[test]
+    fn should_follow_turkish_business_calendar() {
+        let mut business_calendar = BusinessCalendar::turkish();
+        business_calendar.add_holiday("09-03-2027").unwrap();
+
+        assert_eq!(Ok(false), business_calendar.is_business_day("29-10-2021"));
+        assert_eq!(Ok(false), business_calendar.is_business_day("15-07-2021"));
+        assert_eq!(Ok(true), business_calendar.is_business_day("15-07-2016"));
+        assert_eq!(Ok(false), business_calendar.is_business_day("21-04-2023"));
+        assert_eq!(Ok(true), business_calendar.is_business_day("20-04-2023"));
+        assert_eq!(Ok(false), business_calendar.is_business_day("09-11-2011"));
+        assert_eq!(Ok(true), business_calendar.is_business_day("10-11-2011"));
+        assert_eq!(Ok(false), business_calendar.is_business_day("03-01-2007"));
+        assert_eq!(Ok(false), business_calendar.is_business_day("09-03-2027"));
+        assert_eq!(Ok(false), business_calendar.is_business_day("17-12-2011"));
+        assert_eq!(Ok(true), BusinessCalendar::new().is_business_day("29-10-2021"));
+    }
+
+    #[test]
+    fn should_report_business_day_problems() {
+        // 25-10-2021 is Monday and 29-10-2021 is Republic Day.
+        let observations = single_series(&[
+            ("25-10-2021", Some(8.9)),
+            ("26-10-2021", None),
+            ("27-10-2021", None),
+            ("29-10-2021", None),
+            ("30-10-2021", None),
+            ("01-11-2021", Some(9.1)),
+            ("01-11-2021", Some(9.1)),
+            ("03-11-2021", Some(9.2)),
+        ]);
+        let date_range = DateRange::from("25-10-2021", "03-11-2021").unwrap();
+
+        let report = check(
+            &observations,
+            "TP.DK.USD.A",
+            DataFrequency::Business,
+            &date_range,
+            &BusinessCalendar::turkish(),
+        ).unwrap();
+
+        assert_eq!(vec!["28-10-2021".to_string(), "02-11-2021".to_string()], report.missing_periods);
+        assert_eq!(vec!["01-11-2021".to_string()], report.duplicate_dates);
+        assert_eq!(
+            vec![NullRun { start_date: "26-10-2021".to_string(), end_date: "27-10-2021".to_string(), length: 2 }],
+            report.null_runs
+        );
+
+        assert_eq!(vec![("26-10-2021", "28-10-2021"), ("02-11-2021", "02-11-2021")], dates_of(&report.gap_ranges()));
+    }
+
+    #[test]
+    fn should_check_periods_of_lower_frequencies() {
+        let observations = single_series(&[("2020-Q1", Some(1.0)), ("2020-Q3", Some(3.0)), ("2020-Q4", Some(4.0))]);
+        let date_range = DateRange::from("01-01-2020", "31-12-2020").unwrap();
+
+        let report =
+            check(&observations, "TP_DK_USD_A", DataFrequency::Quarterly, &date_range, &BusinessCalendar::new())
+                .unwrap();
+
+        assert_eq!(vec!["01-04-2020".to_string()], report.missing_periods);
+        assert!(!report.is_complete());
+
+        let observations = single_series(&[("03-01-2020", Some(1.0)), ("17-01-2020", Some(3.0))]);
+        let date_range = DateRange::from("01-01-2020", "19-01-2020").unwrap();
+
+        let report =
+            check(&observations, "TP_DK_USD_A", DataFrequency::WeeklyFriday, &date_range, &BusinessCalendar::new())
+                .unwrap();
+
+        // 30-12-2019 and 06-01-2020 are the Mondays of the first and second weeks.
+        assert_eq!(vec!["06-01-2020".to_string()], report.missing_periods);
+        assert_eq!(vec![("06-01-2020", "12-01-2020")], dates_of(&report.gap_ranges()));
+    }
+}
@@ -0,0 +1,200 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+
+/// is the offset of Istanbul time from UTC in seconds. Turkey uses UTC+3 all year round since 2016.
+pub(crate) const ISTANBUL_UTC_OFFSET: i64 = 3 * 60 * 60;
+
+pub(crate) const SECONDS_PER_DAY: i64 = 24 * 60 * 60;
+
+
+/// is a calendar day given with year, month and day numbers which is comparable in time order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub(crate) struct Day {
+    pub(crate) year: i32,
+    pub(crate) month: u32,
+    pub(crate) day: u32,
+}
+
+impl Day {
+    /// parses "day-month-year" formatted date such as "13-12-2011".
+    pub(crate) fn parse(date: &str) -> Option<Day> {
+        let mut parts = date.trim().split('-');
+
+        let day = parts.next()?.parse::<u32>().ok()?;
+        let month = parts.next()?.parse::<u32>().ok()?;
+        let year = parts.next()?.parse::<i32>().ok()?;
+
+        if parts.next().is_some() { return None }
+
+        Day::checked(year, month, day)
+    }
+
+    /// creates a day if it exists in the calendar.
+    fn checked(year: i32, month: u32, day: u32) -> Option<Day> {
+        if month == 0 || month > 12 || day == 0 || day > days_in_month(year, month) { return None }
+
+        Some(Day { year, month, day })
+    }
+
+    /// parses the date of an observation as the first day of its period.
+    ///
+    /// EVDS gives daily and weekly dates as "13-12-2011", monthly dates as "2011-12", quarterly dates as "2011-Q4",
+    /// semiannual dates as "2011-S2" and annual dates as "2011".
+    pub(crate) fn parse_period(date: &str) -> Option<Day> {
+        let date = date.trim();
+
+        if let Some(day) = Day::parse(date) { return Some(day) }
+
+        let mut parts = date.splitn(2, '-');
+        let year = parts.next()?.parse::<i32>().ok()?;
+
+        let month = match parts.next() {
+            None => 1,
+            Some(period) => match period.chars().next()? {
+                'Q' => 3 * period[1..].parse::<u32>().ok().filter(|quarter| (1..=4).contains(quarter))? - 2,
+                'S' => 6 * period[1..].parse::<u32>().ok().filter(|half| (1..=2).contains(half))? - 5,
+                _ => period.parse::<u32>().ok().filter(|month| (1..=12).contains(month))?,
+            },
+        };
+
+        Some(Day { year, month, day: 1 })
+    }
+
+    /// creates a day from the number of days since 01-01-1970.
+    pub(crate) fn from_days(days: i64) -> Day {
+        // Civil from days algorithm of Howard Hinnant.
+        let z = days + 719_468;
+        let era = z.div_euclid(146_097);
+        let day_of_era = z.rem_euclid(146_097);
+        let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+        let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+        let day = (day_of_year - (153 * shifted_month + 2) / 5 + 1) as u32;
+        let month = if shifted_month < 10 { shifted_month + 3 } else { shifted_month - 9 } as u32;
+        let year = (year_of_era + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+
+        Day { year, month, day }
+    }
+
+    /// gives the number of days since 01-01-1970.
+    pub(crate) fn to_days(self) -> i64 {
+        // Days from civil algorithm of Howard Hinnant.
+        let year = self.year as i64 - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let year_of_era = year.rem_euclid(400);
+        let shifted_month = (self.month as i64 + 9) % 12;
+        let day_of_year = (153 * shifted_month + 2) / 5 + self.day as i64 - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// gives the day that is the given number of days later. Negative numbers give earlier days.
+    pub(crate) fn add_days(self, days: i64) -> Day {
+        Day::from_days(self.to_days() + days)
+    }
+
+    /// gives weekday number where Monday is 1 and Sunday is 7.
+    pub(crate) fn weekday(self) -> u32 {
+        // 01-01-1970 is Thursday.
+        ((self.to_days() + 3).rem_euclid(7) + 1) as u32
+    }
+
+    /// checks the day is Saturday or Sunday.
+    pub(crate) fn is_weekend(self) -> bool {
+        self.weekday() >= 6
+    }
+
+    /// formats the day as "day-month-year" such as "13-12-2011".
+    pub(crate) fn format(self) -> String {
+        format!("{:02}-{:02}-{:04}", self.day, self.month, self.year)
+    }
+
+    /// formats the day as "year-month-day" such as "2011-12-13", which sorts in time order as text.
+    #[cfg_attr(not(feature = "store"), allow(dead_code))]
+    pub(crate) fn format_iso(self) -> String {
+        format!("{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+
+    /// parses "year-month-day" formatted date such as "2011-12-13".
+    #[cfg_attr(not(feature = "store"), allow(dead_code))]
+    pub(crate) fn parse_iso(date: &str) -> Option<Day> {
+        let mut parts = date.trim().split('-');
+
+        let year = parts.next()?.parse::<i32>().ok()?;
+        let month = parts.next()?.parse::<u32>().ok()?;
+        let day = parts.next()?.parse::<u32>().ok()?;
+
+        if parts.next().is_some() { return None }
+
+        Day::checked(year, month, day)
+    }
+}
+
+
+/// gives the number of days of the given month.
+pub(crate) fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if year % 4 == 0 && (year % 100 != 0 || year % 400 == 0) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// gives seconds passed since 01-01-1970 in Istanbul time.
+pub(crate) fn istanbul_seconds_now() -> i64 {
+    let utc_seconds = match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(_) => 0,
+    };
+
+    utc_seconds + ISTANBUL_UTC_OFFSET
+}
+
+/// gives current day in Istanbul time, which is the time EVDS publishes its data in.
+pub(crate) fn today_in_istanbul() -> Day {
+    Day::from_days(istanbul_seconds_now().div_euclid(SECONDS_PER_DAY))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_convert_days_to_dates() {
+        assert_eq!(Day { year: 1970, month: 1, day: 1 }, Day::from_days(0));
+        assert_eq!(Day { year: 2011, month: 12, day: 13 }, Day::from_days(15_321));
+        assert_eq!(Day { year: 2020, month: 2, day: 29 }, Day::from_days(18_321));
+        assert_eq!(15_321, Day::parse("13-12-2011").unwrap().to_days());
+        assert_eq!("01-03-2020", Day::parse("28-02-2020").unwrap().add_days(2).format());
+        assert_eq!("2004-12-31", Day::parse("01-01-2005").unwrap().add_days(-1).format_iso());
+        assert_eq!(Day::parse("13-12-2011"), Day::parse_iso("2011-12-13"));
+    }
+
+    #[test]
+    fn should_validate_and_compare_dates() {
+        assert!(Day::parse("29-02-2021").is_none());
+        assert!(Day::parse("29-02-2020").is_some());
+        assert!(Day::parse("2020-02-01").is_none());
+        assert!(Day::parse("13-12-2011").unwrap() < Day::parse("01-01-2012").unwrap());
+
+        // 13-12-2011 is Tuesday and 17-12-2011 is Saturday.
+        assert_eq!(2, Day::parse("13-12-2011").unwrap().weekday());
+        assert!(Day::parse("17-12-2011").unwrap().is_weekend());
+    }
+
+    #[test]
+    fn should_parse_periods() {
+        assert_eq!(Day::parse("13-12-2011"), Day::parse_period("13-12-2011"));
+        assert_eq!(Some(Day { year: 2011, month: 12, day: 1 }), Day::parse_period("2011-12"));
+        assert_eq!(Some(Day { year: 2011, month: 4, day: 1 }), Day::parse_period("2011-4"));
+        assert_eq!(Some(Day { year: 2011, month: 10, day: 1 }), Day::parse_period("2011-Q4"));
+        assert_eq!(Some(Day { year: 2011, month: 7, day: 1 }), Day::parse_period("2011-S2"));
+        assert_eq!(Some(Day { year: 2011, month: 1, day: 1 }), Day::parse_period("2011"));
+        assert!(Day::parse_period("2011-13").is_none());
+        assert!(Day::parse_period("2011-Q5").is_none());
+    }
+}
@@ -1,6 +1,8 @@
 use std::str;
 
 mod date_validation_basics;
+/// contains calendar arithmetic on days and the current day in Istanbul time.
+pub(crate) mod calendar;
 
 use self::date_validation_basics::*;
 
@@ -49,7 +51,7 @@ impl Date {
         Err(ReturnError::InvalidDate)
     }
 
-    fn get_date(&self) -> &str {
+    pub(crate) fn get_date(&self) -> &str {
         &self.0
     }
 }
@@ -164,7 +166,7 @@ impl DateRange {
     }
 
     /// gives dates in a tuple format.
-    fn get_dates(&self) -> (&str, &str) {
+    pub(crate) fn get_dates(&self) -> (&str, &str) {
         (&self.start_date, &self.end_date)
     }
 }
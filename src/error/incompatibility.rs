@@ -0,0 +1,47 @@
+use std::fmt;
+
+use crate::evds_currency::frequency_formulas::{DataFrequency, Formula};
+
+
+/// explains why the elements of frequency formulas do not fit each other or the requested series.
+///
+/// This enum is given by [`ReturnError::IncompatibleAdvancedProcesses`](enum@crate::error::ReturnError) when
+/// [`AdvancedProcesses`](struct@crate::evds_currency::frequency_formulas::AdvancedProcesses) are validated. It is
+/// also re-exported from [`frequency_formulas`](crate::evds_currency::frequency_formulas).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Incompatibility {
+    /// the data frequency is more frequent than the frequency of the series, which cannot be disaggregated.
+    FinerThanSeries { series_frequency: DataFrequency, data_frequency: DataFrequency },
+    /// year to year formulas compare a period with the same period of the previous year, which daily and weekly
+    /// periods do not have.
+    YearToYearFormula { formula: Formula, data_frequency: DataFrequency },
+    /// sums of exchange rates, either cumulative or moving, do not have a meaning.
+    SumOfRates,
+    /// automatic redenomination computes frequency formulas separately on each side of 01-01-2005, which only keeps
+    /// levels of periods that do not span that day.
+    AcrossRedenomination { formula: Formula, data_frequency: DataFrequency },
+}
+
+impl fmt::Display for Incompatibility {
+    fn fmt(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Incompatibility::FinerThanSeries { series_frequency, data_frequency } => write!(
+                formatter,
+                "{:?} frequency is more frequent than {:?} frequency of the series",
+                data_frequency, series_frequency
+            ),
+            Incompatibility::YearToYearFormula { formula, data_frequency } => write!(
+                formatter,
+                "{:?} formula needs periods repeating each year, which {:?} frequency does not have",
+                formula, data_frequency
+            ),
+            Incompatibility::SumOfRates => write!(formatter, "sums of exchange rates do not have a meaning"),
+            Incompatibility::AcrossRedenomination { formula, data_frequency } => write!(
+                formatter,
+                "{:?} formula with {:?} frequency cannot be computed across the redenomination on 01-01-2005, which \
+                needs Level formula and Monthly or less frequent data",
+                formula, data_frequency
+            ),
+        }
+    }
+}
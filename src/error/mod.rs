@@ -1,12 +1,21 @@
+/// explains why frequency formulas are rejected.
+mod incompatibility;
+
 use std::{cmp, error, fmt};
 
+pub use self::incompatibility::Incompatibility;
+
 
 /// contains specified error options returned from various [`tcmb_evds`](crate) operations.
 ///
 /// This enum is returned with an error option to specify what goes wrong.
 ///
 /// Only **ResponseError** option of this enum contains an error message which is a returned response 
-/// against incorrect request.
+/// against incorrect request. Similarly, **StoreError** and **ExportError** contain the message of the failed
+/// database and export operations respectively, **ConflictingObservations** names the series and date merged
+/// responses disagree on, while **MissingOption** and **ConflictingOptions** name the options
+/// a builder rejects. **IncompatibleAdvancedProcesses** explains why frequency formulas are rejected and
+/// **UnknownFrequency** gives the frequency of series metadata that is not a frequency of EVDS.
 #[derive(Debug)]
 pub enum ReturnError {
     InvalidApiKeyOrBadInternetConnection,
@@ -18,6 +27,7 @@ pub enum ReturnError {
     InvalidDate,
     EmptyExchangeType,
     EmptyCurrencyCodes,
+    InvalidCurrencyCode,
     SingleExchangeTypeExpected,
     SingleDateExpected,
     MultipleDateExpected,
@@ -25,11 +35,25 @@ pub enum ReturnError {
     NotFound,
     UnableToRequest,
     UnableToSetUrl,
+    UnableToConfigureClient,
+    InvalidRateLimit,
     FailedToApplyRequest,
     FailedToSaveReceivedData,
     ResponseError(String),
+    UnableToParseResponse,
+    UnableToMergeResponses,
+    ConflictingObservations(String),
+    UnableToAccessCache,
+    NotCached,
+    StoreError(String),
+    ExportError(String),
+    NoExchangeRate,
+    MissingOption(String),
+    ConflictingOptions(String),
+    IncompatibleAdvancedProcesses(Incompatibility),
     EmptyResponse,
     ForbiddenRequest,
+    UnknownFrequency(String),
 }
 
 impl ReturnError {
@@ -45,6 +69,7 @@ impl ReturnError {
             ReturnError::InvalidDate => return "Error: Invalid date.".to_string(),
             ReturnError::EmptyExchangeType => return "Error: Empty exchange type.".to_string(),
             ReturnError::EmptyCurrencyCodes => return "Error: Empty currency codes.".to_string(),
+            ReturnError::InvalidCurrencyCode => return "Error: Invalid currency code.".to_string(),
             ReturnError::SingleExchangeTypeExpected => return "Error: Single exchange type expected.".to_string(),
             ReturnError::SingleDateExpected => return "Error: Single date expected.".to_string(),
             ReturnError::MultipleDateExpected => return "Error: Multiple date expected.".to_string(),
@@ -52,13 +77,31 @@ impl ReturnError {
             ReturnError::NotFound => return "Error: 404 not found.".to_string(),
             ReturnError::UnableToRequest => return "Error: Unable to ask for a HTTP GET request.".to_string(),
             ReturnError::UnableToSetUrl => return "Error: Unable to appropriately set url.".to_string(),
+            ReturnError::UnableToConfigureClient => return "Error: Unable to apply client configuration.".to_string(),
+            ReturnError::InvalidRateLimit => return "Error: Invalid rate limit.".to_string(),
             ReturnError::FailedToApplyRequest => return "Error: Failed to apply HTTP request.
             \nHelp: please check the internet connection or the validity of given url.".to_string(),
             ReturnError::FailedToSaveReceivedData => return "Error: Failed to save received data.".to_string(),
             ReturnError::ResponseError(message) => return message.to_owned(),
+            ReturnError::UnableToParseResponse => return "Error: Unable to parse the response.".to_string(),
+            ReturnError::UnableToMergeResponses => return "Error: Unable to merge responses by date.".to_string(),
+            ReturnError::ConflictingObservations(message) => {
+                return format!("Error: Conflicting observations: {}.", message)
+            },
+            ReturnError::UnableToAccessCache => return "Error: Unable to access the response cache.".to_string(),
+            ReturnError::NotCached => return "Error: The response is not cached.".to_string(),
+            ReturnError::StoreError(message) => return format!("Error: Store failure: {}.", message),
+            ReturnError::ExportError(message) => return format!("Error: Export failure: {}.", message),
+            ReturnError::NoExchangeRate => return "Error: No exchange rate is found for the date.".to_string(),
+            ReturnError::MissingOption(option) => return format!("Error: Missing option: {} is not given.", option),
+            ReturnError::ConflictingOptions(reason) => return format!("Error: Conflicting options: {}.", reason),
+            ReturnError::IncompatibleAdvancedProcesses(incompatibility) => {
+                return format!("Error: Incompatible advanced processes: {}.", incompatibility)
+            },
             ReturnError::EmptyResponse => return "Error: Empty page returned.".to_string(),
             ReturnError::ForbiddenRequest => return "Error: The request is forbidden.
             \nHelp: please check given data series is wether single or not.".to_string(),
+            ReturnError::UnknownFrequency(frequency) => return format!("Error: Unknown frequency: {}.", frequency),
         }
     }
 }
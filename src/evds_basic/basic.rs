@@ -1,3 +1,7 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+
+use crate::common::Evds;
 use crate::error::ReturnError;
 #[cfg(feature = "async_mode")]
 use crate::request_async;
@@ -100,9 +104,9 @@ pub(crate) fn check_response(response: &str, function: Function) -> Result<(), R
 ///
 /// This function is applicable for async operations and configured for evds basic operations.
 #[cfg(feature = "async_mode")]
-fn make_request_async(url: &str, function: Function) -> Result<String, ReturnError> {
+fn make_request_async(url: &str, function: Function, evds: &Evds) -> Result<String, ReturnError> {
     
-    let response = request_async::do_request(&url)?;
+    let response = request_async::do_request(&url, evds.get_transport())?;
     
     check_response(&response, function)?;
     
@@ -117,9 +121,9 @@ fn make_request_async(url: &str, function: Function) -> Result<String, ReturnErr
 ///
 /// This function is applicable for sync operations and configured for evds basic operations.
 #[cfg(feature = "sync_mode")]
-fn make_request_sync(url: &str, function: Function) -> Result<String, ReturnError> {
+fn make_request_sync(url: &str, function: Function, evds: &Evds) -> Result<String, ReturnError> {
     
-    let response = request_sync::do_request(&url)?;
+    let response = request_sync::do_request(&url, evds.get_transport())?;
 
     check_response(&response, function)?;
 
@@ -131,11 +135,96 @@ fn make_request_sync(url: &str, function: Function) -> Result<String, ReturnErro
 /// The most important feature of this function is that the functionality of the function can be changed when 
 /// the crate is compiled according to preferred compiling feature, thanks to the conditional compiling of Rust.
 ///
-/// This function is configured for evds currency operations.
-pub(crate) fn make_request(url: &str, function: Function) -> Result<String, ReturnError> {
-    #[cfg(feature = "async_mode")]
-    return make_request_async(url, function);
+/// This function is configured for evds currency operations. Responses are served from the response cache of the
+/// Evds when there is one.
+pub(crate) fn make_request(url: &str, function: Function, evds: &Evds) -> Result<String, ReturnError> {
+    evds.cached(url, || {
+        #[cfg(feature = "async_mode")]
+        return make_request_async(url, function, evds);
+
+        #[cfg(feature = "sync_mode")]
+        return make_request_sync(url, function, evds);
+    })
+}
+
+/// applies the given operation to each item with at most `concurrency` threads and returns the results in input order.
+///
+/// Worker threads take the next unprocessed item until all items are processed. Zero concurrency is treated as 1.
+pub(crate) fn run_concurrently<T, R, F>(items: &[T], concurrency: usize, operation: F) -> Vec<R>
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+{
+    let next_index = AtomicUsize::new(0);
+    let worker_count = concurrency.max(1).min(items.len());
+
+    let mut indexed_results: Vec<(usize, R)> = thread::scope(|scope| {
+        let workers: Vec<_> = (0..worker_count).map(|_| {
+            scope.spawn(|| {
+                let mut results = Vec::new();
+
+                loop {
+                    let index = next_index.fetch_add(1, Ordering::SeqCst);
+
+                    let item = match items.get(index) {
+                        Some(item) => item,
+                        None => break,
+                    };
+
+                    results.push((index, operation(item)));
+                }
+
+                results
+            })
+        }).collect();
+
+        workers.into_iter()
+            .flat_map(|worker| worker.join().unwrap_or_else(|panic| std::panic::resume_unwind(panic)))
+            .collect()
+    });
+
+    indexed_results.sort_by_key(|(index, _)| *index);
+
+    indexed_results.into_iter().map(|(_, result)| result).collect()
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::AtomicUsize;
+    use std::time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn should_keep_input_order() {
+        let items: Vec<u64> = (0..50).collect();
 
-    #[cfg(feature = "sync_mode")]
-    return make_request_sync(url, function);
+        let results = run_concurrently(&items, 8, |item| {
+            thread::sleep(Duration::from_millis(50 - item));
+            item * 2
+        });
+
+        assert_eq!(items.iter().map(|item| item * 2).collect::<Vec<_>>(), results);
+    }
+
+    #[test]
+    fn should_bound_concurrency() {
+        let in_flight = AtomicUsize::new(0);
+        let max_in_flight = AtomicUsize::new(0);
+
+        let results = run_concurrently(&[(); 20], 3, |_| {
+            let current = in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            max_in_flight.fetch_max(current, Ordering::SeqCst);
+
+            thread::sleep(Duration::from_millis(10));
+
+            in_flight.fetch_sub(1, Ordering::SeqCst);
+        });
+
+        assert_eq!(20, results.len());
+        assert_eq!(3, max_in_flight.load(Ordering::SeqCst));
+        assert!(run_concurrently(&Vec::<u8>::new(), 0, |item| *item).is_empty());
+    }
 }
@@ -4,6 +4,9 @@ mod basic;
 use crate::date;
 use crate::common;
 use crate::error::ReturnError;
+use crate::evds_currency::frequency_formulas::AdvancedProcesses;
+use crate::response::{Observations, SeriesMetadata};
+use crate::series_split;
 use crate::traits::MakingUrlFormat;
 
 /// returns data about requested data series.
@@ -15,6 +18,9 @@ use crate::traits::MakingUrlFormat;
 ///
 /// *User is responsible to supply correct and valid* **data series** *argument for this function.*
 ///
+/// Data series exceeding the url limits of [`ClientConfig`](struct@crate::common::ClientConfig) are requested in
+/// several parts and their observations are merged by date into a single response.
+///
 /// # Error
 ///
 /// This function returns an error if some of given parameters is empty, the internet connection is poor or/and
@@ -66,22 +72,353 @@ pub fn get_data<'a>(
     evds: &common::Evds,
 ) -> Result<String, ReturnError> {
 
+    let urls = data_urls(data_series, date_preference, None, evds)?;
+
+    // Series exceeding the url limits of the client configuration are requested in parts and merged by date.
+    series_split::request_urls(&urls, evds, |url| basic::make_request(url, basic::Function::OneOfOtherFunctions, evds))
+}
+
+/// generates the urls of the requests of the data series in the order they are sent, with the frequency formulas if
+/// there are.
+///
+/// # Error
+///
+/// This function returns an error if the data series is empty.
+fn data_urls(
+    data_series: &str,
+    date_preference: &date::DatePreference,
+    advanced_processes: Option<&AdvancedProcesses>,
+    evds: &common::Evds,
+) -> Result<Vec<String>, ReturnError> {
+
+    basic::check_emptiness(data_series)?;
+
     let dates_as_url = date_preference.generate_url_format();
     let return_format_as_url = evds.get_return_format_as_url();
     let api_key_as_url = evds.get_api_key_as_url();
 
+    let compose_url = |series: &str| {
+        let url = format!(
+            "https://evds2.tcmb.gov.tr/service/evds/series={}&{}&{}&{}",
+            series,
+            dates_as_url,
+            return_format_as_url,
+            api_key_as_url,
+        );
+
+        match advanced_processes {
+            Some(advanced_processes) => {
+                // EVDS takes an aggregation type and a formula for each series, separated as the series are.
+                let for_each_series = |value: String| vec![value; series.split('-').count()].join("-");
+
+                format!(
+                    "{}&aggregationTypes={}&formulas={}&{}",
+                    url,
+                    for_each_series(advanced_processes.aggregation_type.to_string()),
+                    for_each_series(advanced_processes.formula.to_string()),
+                    advanced_processes.get_data_frequency_as_url_format(),
+                )
+            },
+            None => url,
+        }
+    };
+
+    Ok(series_split::urls_in_parts(data_series, compose_url, evds))
+}
+
+/// returns requested data series with frequency formulas after checking the formulas against the frequency of each
+/// series.
+///
+/// The frequencies are read from the metadata of the series in the given data group, as
+/// [`get_series_metadata`](fn@get_series_metadata) gives, and each series is checked by
+/// [`validate_for_series`](fn@crate::evds_currency::frequency_formulas::AdvancedProcesses::validate_for_series)
+/// before the data is requested. The same frequency formulas are applied to each series.
+///
+/// *User is responsible to supply correct and valid* **data series** *and* **data group** *arguments for this
+/// function.*
+///
+/// # Error
+///
+/// This function returns the errors of [`get_series_metadata`](fn@get_series_metadata) and [`get_data`](fn@get_data),
+/// an error if a series is not in the data group and an error if the frequency formulas do not fit a series.
+///
+/// # Example
+///
+/// Follow [`Evds`](crate::common::Evds) for full and detailed implementation of **evds** argument.
+///
+/// ```
+/// #   use tcmb_evds::date::{DateRange, DatePreference};
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::evds_basic;
+///     use tcmb_evds::evds_currency::frequency_formulas::*;
+///
+///
+/// #   let date_range =
+/// #       if let Ok(date_range) = DateRange::from("01-01-2020", "31-12-2020") { date_range }
+/// #       else { return };
+/// #   let date_preference = DatePreference::Multiple(date_range);
+/// #
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+///     let advanced_processes = AdvancedProcesses::from(AggregationType::End, Formula::Level, DataFrequency::Monthly);
+///
+///     let result =
+///         evds_basic::get_advanced_data("TP.DK.USD.A", "bie_dkdovytl", &date_preference, &advanced_processes, &evds);
+///
+///     let data = match result {
+///         Err(error) => {
+///             println!("{}", error.to_string());
+///             return;
+///         },
+///         Ok(data) => data,
+///     };
+/// ```
+pub fn get_advanced_data(
+    data_series: &str,
+    data_group: &str,
+    date_preference: &date::DatePreference,
+    advanced_processes: &AdvancedProcesses,
+    evds: &common::Evds,
+) -> Result<String, ReturnError> {
+
     basic::check_emptiness(data_series)?;
 
-    let url =
-    format!(
-        "https://evds2.tcmb.gov.tr/service/evds/series={}&{}&{}&{}",
-        data_series,
-        dates_as_url,
-        return_format_as_url,
-        api_key_as_url,
-    );
+    let series_list = get_series_metadata(data_group, evds)?;
+
+    validate_series(data_series, &series_list, advanced_processes)?;
+
+    let urls = data_urls(data_series, date_preference, Some(advanced_processes), evds)?;
+
+    series_split::request_urls(&urls, evds, |url| basic::make_request(url, basic::Function::OneOfOtherFunctions, evds))
+}
+
+/// checks the frequency formulas against the metadata of each series of the data series.
+///
+/// # Error
+///
+/// This function returns an error if a series does not have metadata or the frequency formulas do not fit it.
+fn validate_series(
+    data_series: &str,
+    series_list: &[SeriesMetadata],
+    advanced_processes: &AdvancedProcesses,
+) -> Result<(), ReturnError> {
+
+    for series in data_series.split('-').map(str::trim) {
+        let metadata = series_list.iter()
+            .find(|metadata| metadata.code.trim() == series)
+            .ok_or(ReturnError::InvalidSeries)?;
+
+        advanced_processes.validate_for_series(metadata)?;
+    }
+
+    Ok(())
+}
+
+/// composes the requests of [`get_data`](fn@get_data) without sending them, with the api key redacted.
+///
+/// The urls are composed from the arguments as [`get_data`](fn@get_data) composes them, without any I/O.
+///
+/// # Error
+///
+/// This function returns an error if the data series is empty.
+///
+/// # Example
+///
+/// Follow [`Evds`](crate::common::Evds) for full and detailed implementation of **evds** argument.
+///
+/// ```
+/// #   use tcmb_evds::date::{Date, DatePreference};
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::evds_basic;
+///
+///
+/// #   let date_preference =
+/// #       if let Ok(date) = Date::from("13-12-2011") { DatePreference::Single(date) }
+/// #       else { return };
+/// #
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Xml);
+///     let request = match evds_basic::build_request("TP.DK.USD.A-TP.DK.EUR.A", &date_preference, &evds) {
+///         Err(error) => {
+///             println!("{}", error.to_string());
+///             return;
+///         },
+///         Ok(request) => request,
+///     };
+///
+///     println!("{}", request.to_url());
+/// ```
+pub fn build_request(
+    data_series: &str,
+    date_preference: &date::DatePreference,
+    evds: &common::Evds,
+) -> Result<common::Request, ReturnError> {
+    Ok(evds.compose_request(data_urls(data_series, date_preference, None, evds)?))
+}
+
+/// composes the requests of [`get_advanced_data`](fn@get_advanced_data) without sending them, with the api key
+/// redacted.
+///
+/// The frequency formulas are not checked against the metadata of the series, since the metadata is given by a
+/// request.
+///
+/// # Error
+///
+/// This function returns an error if the data series is empty.
+pub fn build_advanced_request(
+    data_series: &str,
+    date_preference: &date::DatePreference,
+    advanced_processes: &AdvancedProcesses,
+    evds: &common::Evds,
+) -> Result<common::Request, ReturnError> {
+    Ok(evds.compose_request(data_urls(data_series, date_preference, Some(advanced_processes), evds)?))
+}
+
+/// returns typed observations of requested data series.
+///
+/// This function makes the same request as [`get_data`](fn@get_data) and parses the response into
+/// [`Observations`](struct@crate::response::Observations) according to the return format of the given
+/// [`Evds`](crate::common::Evds).
+///
+/// # Error
+///
+/// This function returns the errors of [`get_data`](fn@get_data) and an error if the response cannot be parsed.
+///
+/// # Example
+///
+/// Follow [`Evds`](crate::common::Evds) for full and detailed implementation of **evds** argument.
+///
+/// ```
+/// #   use tcmb_evds::date::{DateRange, DatePreference};
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::evds_basic;
+///
+///
+/// #   let date_range =
+/// #       if let Ok(date_range) = DateRange::from("01-01-2020", "31-12-2020") { date_range }
+/// #       else { return };
+/// #   let date_preference = DatePreference::Multiple(date_range);
+/// #
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+///
+///
+///     let observations = match evds_basic::get_observations("TP.DK.USD.A-TP.DK.EUR.A", &date_preference, &evds) {
+///         Err(error) => {
+///             println!("{}", error.to_string());
+///             return;
+///         },
+///         Ok(observations) => observations,
+///     };
+///
+///     for observation in &observations.rows {
+///         println!("{}: {:?}", observation.date, observation.values);
+///     }
+/// ```
+pub fn get_observations(
+    data_series: &str,
+    date_preference: &date::DatePreference,
+    evds: &common::Evds,
+) -> Result<Observations, ReturnError> {
+
+    let response = get_data(data_series, date_preference, evds)?;
+
+    Observations::parse_series(&response, evds.get_return_format(), data_series)
+}
+
+/// returns requested data series as a Polars `DataFrame`.
+///
+/// The frame has a "date" column of the `Date` type and a `Float64` column for each series named by its series code.
+/// Missing values are nulls. Follow [`Observations::to_polars`](fn@crate::response::Observations::to_polars) for
+/// details and [`get_decimal_data_frame`](fn@get_decimal_data_frame) for `Decimal` columns.
+///
+/// This function is available with the `dataframe` feature.
+///
+/// # Error
+///
+/// This function returns the errors of [`get_observations`](fn@get_observations) and an error if a date of the
+/// response is not a date or a period of EVDS.
+///
+/// # Example
+///
+/// Follow [`Evds`](crate::common::Evds) for full and detailed implementation of **evds** argument.
+///
+/// ```
+/// #   use tcmb_evds::date::{DateRange, DatePreference};
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::evds_basic;
+///
+///
+/// #   let date_range =
+/// #       if let Ok(date_range) = DateRange::from("01-01-2020", "31-12-2020") { date_range }
+/// #       else { return };
+/// #   let date_preference = DatePreference::Multiple(date_range);
+/// #
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+///
+///
+///     let data_frame = match evds_basic::get_data_frame("TP.DK.USD.A-TP.DK.EUR.A", &date_preference, &evds) {
+///         Err(error) => {
+///             println!("{}", error.to_string());
+///             return;
+///         },
+///         Ok(data_frame) => data_frame,
+///     };
+///
+///     println!("{}", data_frame.height());
+/// ```
+#[cfg(feature = "dataframe")]
+pub fn get_data_frame(
+    data_series: &str,
+    date_preference: &date::DatePreference,
+    evds: &common::Evds,
+) -> Result<polars::prelude::DataFrame, ReturnError> {
+
+    let observations = get_observations(data_series, date_preference, evds)?;
+
+    let series_codes: Vec<&str> = data_series.split('-').collect();
+
+    observations.to_polars_named(&series_codes)
+}
+
+/// returns requested data series as a Polars `DataFrame` with exact `Decimal` columns.
+///
+/// The frame is given as [`get_data_frame`](fn@get_data_frame) gives, except that values are not rounded through
+/// `f64`. Follow [`Observations::parse_decimal`](fn@crate::response::Observations::parse_decimal) for details.
+///
+/// This function is available with both of the `dataframe` and `decimal` features.
+///
+/// # Error
+///
+/// This function returns the errors of [`get_data`](fn@get_data) and an error if the response cannot be parsed or a
+/// date of the response is not a date or a period of EVDS.
+#[cfg(all(feature = "dataframe", feature = "decimal"))]
+pub fn get_decimal_data_frame(
+    data_series: &str,
+    date_preference: &date::DatePreference,
+    evds: &common::Evds,
+) -> Result<polars::prelude::DataFrame, ReturnError> {
+
+    let response = get_data(data_series, date_preference, evds)?;
+
+    let observations = Observations::parse_decimal_series(&response, evds.get_return_format(), data_series)?;
+
+    let series_codes: Vec<&str> = data_series.split('-').collect();
 
-    basic::make_request(&url, basic::Function::OneOfOtherFunctions)
+    observations.to_polars_named(&series_codes)
 }
 
 /// returns requested data group.
@@ -162,7 +499,7 @@ pub fn get_data_group(
         api_key_as_url,
     );
 
-    basic::make_request(&url, basic::Function::GetDataGroup)
+    basic::make_request(&url, basic::Function::GetDataGroup, evds)
 }
 
 /// returns all requested categories of EVDS.
@@ -214,7 +551,7 @@ pub fn get_categories(evds: &common::Evds) -> Result<String, ReturnError> {
         return_format_as_url,
     };
 
-    basic::make_request(&url, basic::Function::OneOfOtherFunctions)
+    basic::make_request(&url, basic::Function::OneOfOtherFunctions, evds)
 }
 
 /// returns specified data groups.
@@ -283,7 +620,7 @@ pub fn get_advanced_data_group(
         return_format_as_url,
     );
 
-    basic::make_request(&url, basic::Function::OneOfOtherFunctions)
+    basic::make_request(&url, basic::Function::OneOfOtherFunctions, evds)
 }
 
 /// returns all usable series list.
@@ -349,5 +686,247 @@ pub fn get_series_list(
         code,
     );
 
-    basic::make_request(&url, basic::Function::GetSeriesList)
+    basic::make_request(&url, basic::Function::GetSeriesList, evds)
+}
+
+/// returns typed metadata of the series in requested data group.
+///
+/// This function makes the same request as [`get_series_list`](fn@get_series_list) and parses the response into
+/// [`SeriesMetadata`](struct@crate::response::SeriesMetadata) list. The declared frequency of each series is given by
+/// [`data_frequency`](fn@crate::response::SeriesMetadata::data_frequency).
+///
+/// # Error
+///
+/// This function returns the errors of [`get_series_list`](fn@get_series_list) and an error if the response cannot
+/// be parsed.
+///
+/// # Example
+///
+/// Follow [`Evds`](crate::common::Evds) for full and detailed implementation of **evds** argument.
+///
+/// ```
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::evds_basic;
+///
+///
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+/// #
+///     let series_list = match evds_basic::get_series_metadata("bie_dkdovytl", &evds) {
+///         Err(error) => {
+///             println!("{}", error.to_string());
+///             return;
+///         },
+///         Ok(series_list) => series_list,
+///     };
+///
+///     for series in &series_list {
+///         println!("{}: {:?}", series.code, series.data_frequency());
+///     }
+/// ```
+pub fn get_series_metadata(
+    code: &str,
+    evds: &common::Evds
+) -> Result<Vec<SeriesMetadata>, ReturnError> {
+
+    let response = get_series_list(code, evds)?;
+
+    SeriesMetadata::parse_list(&response, evds.get_return_format())
+}
+
+/// is a data series request used by [`fetch_many`](fn@fetch_many).
+///
+/// It contains the same arguments as [`get_data`](fn@get_data) except **evds**.
+pub struct SeriesQuery {
+    pub data_series: String,
+    pub date_preference: date::DatePreference,
+}
+
+impl SeriesQuery {
+    /// creates a series query from given data series and date preference.
+    ///
+    /// *User is responsible to supply correct and valid* **data series** *argument for this function.*
+    pub fn from(data_series: &str, date_preference: date::DatePreference) -> SeriesQuery {
+        SeriesQuery {
+            data_series: data_series.to_string(),
+            date_preference,
+        }
+    }
+
+    /// composes the requests of the query as [`fetch_many`](fn@fetch_many) sends them, with the api key redacted.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`build_request`](fn@build_request).
+    pub fn build_request(&self, evds: &common::Evds) -> Result<common::Request, ReturnError> {
+        build_request(&self.data_series, &self.date_preference, evds)
+    }
+}
+
+/// returns data about each requested query by making at most `concurrency` requests in parallel.
+///
+/// Results are given in the order of the queries. Each query has its own result, therefore a failed series does not
+/// abort the rest of the batch. Zero concurrency is treated as 1.
+///
+/// Requests pass through the [`RateLimiter`](struct@crate::common::RateLimiter) of the given
+/// [`Evds`](crate::common::Evds), if there is one, and reuse its connections. This function works with both
+/// `async_mode` and `sync_mode` features.
+///
+/// # Example
+///
+/// Follow [`Evds`](crate::common::Evds) for full and detailed implementation of **evds** argument.
+///
+/// ```
+/// #   use tcmb_evds::date::{DateRange, DatePreference};
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::evds_basic::{self, SeriesQuery};
+///
+///
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+/// #
+///     // declaration and assignment of required queries.
+///     let mut queries = Vec::new();
+///
+///     for data_series in ["TP.DK.USD.A", "TP.DK.EUR.A", "TP.DK.GBP.A"] {
+///         let date_range =
+///             if let Ok(date_range) = DateRange::from("01-01-2020", "31-12-2020") { date_range }
+///             else { return };
+///
+///         queries.push(SeriesQuery::from(data_series, DatePreference::Multiple(date_range)));
+///     }
+///
+///
+///     // requesting data section with 4 parallel requests at most.
+///     let results = evds_basic::fetch_many(&queries, 4, &evds);
+///
+///
+///     // error handling or getting the data of each query.
+///     for (query, result) in queries.iter().zip(results) {
+///         match result {
+///             Err(error) => println!("{}: {}", query.data_series, error.to_string()),
+///             Ok(data) => println!("{}: {}", query.data_series, data),
+///         }
+///     }
+/// ```
+pub fn fetch_many(
+    queries: &[SeriesQuery],
+    concurrency: usize,
+    evds: &common::Evds,
+) -> Vec<Result<String, ReturnError>> {
+    basic::run_concurrently(queries, concurrency, |query| {
+        get_data(&query.data_series, &query.date_preference, evds)
+    })
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::common::{ApiKey, Evds, ReturnFormat};
+    use crate::stub_server::StubServer;
+
+    #[test]
+    fn should_return_each_result_in_order() {
+        // The server echoes requested paths, so each response tells which query it was requested for.
+        let server = StubServer::start(Vec::new());
+        let evds = Evds::from(ApiKey::unchecked("abc"), ReturnFormat::Json);
+
+        let series = ["TP.DK.USD.A", "TP.DK.EUR.A", "TP.DK.GBP.A", "TP.DK.CHF.A", "TP.DK.JPY.A", "TP.DK.SEK.A"];
+        let queries: Vec<SeriesQuery> = series.iter()
+            .map(|data_series| SeriesQuery::from(data_series, date::DatePreference::Multiple(date::DateRange::new())))
+            .collect();
+
+        let results = basic::run_concurrently(&queries, 3, |query| {
+            let url = format!("{}series={}", server.url(), query.data_series);
+
+            #[cfg(feature = "async_mode")]
+            return crate::request_async::do_request(&url, evds.get_transport());
+
+            #[cfg(feature = "sync_mode")]
+            return crate::request_sync::do_request(&url, evds.get_transport());
+        });
+
+        let expected: Vec<Result<String, ReturnError>> = series.iter()
+            .map(|data_series| Ok(format!("/series={}", data_series)))
+            .collect();
+
+        assert_eq!(expected, results);
+        assert_eq!(series.len(), server.request_count());
+    }
+
+    #[test]
+    fn should_compose_requests_of_each_query_type() {
+        use crate::evds_currency::frequency_formulas::{AggregationType, DataFrequency, Formula};
+
+        let mut evds = Evds::from(ApiKey::unchecked("secret"), ReturnFormat::Json);
+
+        let mut client_config = common::ClientConfig::new();
+        client_config.change_max_series_per_request(Some(1));
+        evds.change_client_config(client_config);
+
+        let date_preference = date::DatePreference::Single(date::Date::from("13-12-2011").unwrap());
+
+        let request = build_request("TP.DK.USD.A-TP.DK.EUR.A", &date_preference, &evds).unwrap();
+
+        assert_eq!(
+            vec![
+                "https://evds2.tcmb.gov.tr/service/evds/series=TP.DK.USD.A&startDate=13-12-2011&endDate=13-12-2011\
+                &type=json&key=REDACTED",
+                "https://evds2.tcmb.gov.tr/service/evds/series=TP.DK.EUR.A&startDate=13-12-2011&endDate=13-12-2011\
+                &type=json&key=REDACTED",
+            ],
+            request.urls
+        );
+
+        let query = SeriesQuery::from(
+            "TP.DK.USD.A-TP.DK.EUR.A",
+            date::DatePreference::Single(date::Date::from("13-12-2011").unwrap())
+        );
+
+        assert_eq!(Ok(request), query.build_request(&evds));
+
+        let advanced_processes = AdvancedProcesses::from(AggregationType::End, Formula::Level, DataFrequency::Monthly);
+        let request = build_advanced_request("TP.DK.USD.A", &date_preference, &advanced_processes, &evds).unwrap();
+
+        assert_eq!(
+            "https://evds2.tcmb.gov.tr/service/evds/series=TP.DK.USD.A&startDate=13-12-2011&endDate=13-12-2011\
+            &type=json&key=REDACTED&aggregationTypes=last&formulas=0&frequency=5",
+            request.to_url()
+        );
+        assert!(!request.to_string().contains("secret"));
+        assert_eq!(Err(ReturnError::EmptyParameter), build_request("", &date_preference, &evds));
+    }
+
+    #[test]
+    fn should_validate_formulas_against_series_metadata() {
+        use crate::error::Incompatibility;
+        use crate::evds_currency::frequency_formulas::{AggregationType, DataFrequency, Formula};
+
+        let response = r#"[
+            {"SERIE_CODE":"TP.DK.USD.A","FREQUENCY_STR":"İŞ GÜNÜ"},
+            {"SERIE_CODE":"TP.FG.J0","FREQUENCY_STR":"AYLIK"}
+        ]"#;
+        let series_list = SeriesMetadata::parse_list(response, &ReturnFormat::Json).unwrap();
+
+        let weekly = AdvancedProcesses::from(AggregationType::Average, Formula::Level, DataFrequency::WeeklyFriday);
+        let monthly = AdvancedProcesses::from(AggregationType::Average, Formula::Level, DataFrequency::Monthly);
+
+        assert_eq!(Ok(()), validate_series("TP.DK.USD.A", &series_list, &weekly));
+        assert_eq!(Ok(()), validate_series("TP.DK.USD.A-TP.FG.J0", &series_list, &monthly));
+        assert_eq!(
+            Err(ReturnError::IncompatibleAdvancedProcesses(Incompatibility::FinerThanSeries {
+                series_frequency: DataFrequency::Monthly,
+                data_frequency: DataFrequency::WeeklyFriday,
+            })),
+            validate_series("TP.DK.USD.A-TP.FG.J0", &series_list, &weekly)
+        );
+        assert_eq!(Err(ReturnError::InvalidSeries), validate_series("TP.DK.EUR.A", &series_list, &monthly));
+    }
 }
@@ -0,0 +1,425 @@
+use super::frequency_formulas::AdvancedProcesses;
+use super::{CurrencyCode, CurrencyCodes, CurrencySeries, ExchangeType, MultipleCurrencySeries, Redenomination};
+use crate::date::{Date, DatePreference, DateRange};
+use crate::error::ReturnError;
+use crate::traits::MakingList;
+
+
+/// contains the options shared by [`CurrencySeriesBuilder`](struct@CurrencySeriesBuilder) and
+/// [`MultipleCurrencySeriesBuilder`](struct@MultipleCurrencySeriesBuilder).
+struct SeriesOptions {
+    exchange_type: ExchangeType,
+    /// keeps the error of an invalid date until the series are built.
+    date_preference: Option<Result<DatePreference, ReturnError>>,
+    ytl_mode: bool,
+    redenomination: Redenomination,
+}
+
+impl SeriesOptions {
+    /// creates the options without any exchange type and date.
+    fn new() -> SeriesOptions {
+        SeriesOptions {
+            exchange_type: ExchangeType::from_all(false, false, false, false),
+            date_preference: None,
+            ytl_mode: false,
+            redenomination: Redenomination::Manual,
+        }
+    }
+
+    /// gives the exchange type, the date preference, the ytl mode and the redenomination after checking them.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if no exchange type or date is given, a date is invalid or ytl mode is given
+    /// with automatic redenomination.
+    fn validate(self) -> Result<(ExchangeType, DatePreference, bool, Redenomination), ReturnError> {
+        if self.exchange_type.make_required_list().is_empty() {
+            return Err(ReturnError::EmptyExchangeType);
+        }
+
+        let date_preference = self.date_preference.ok_or_else(|| ReturnError::MissingOption("date".to_string()))??;
+
+        if self.ytl_mode && self.redenomination != Redenomination::Manual {
+            return Err(ReturnError::ConflictingOptions(
+                "ytl mode is decided by the automatic redenomination".to_string()
+            ));
+        }
+
+        Ok((self.exchange_type, date_preference, self.ytl_mode, self.redenomination))
+    }
+}
+
+
+/// builds a [`CurrencySeries`](struct@CurrencySeries) option by option and checks the options in
+/// [`build`](fn@CurrencySeriesBuilder::build) before any request is made.
+///
+/// Exchange types are added to each other, so that `.buying().selling()` selects both of them.
+///
+/// # Example
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::evds_currency::{CurrencyCode, CurrencySeries};
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let currency_series = CurrencySeries::builder()
+///         .currency(CurrencyCode::Usd)
+///         .buying()
+///         .range("13-12-2011", "12-12-2012")
+///         .build()?;
+/// #   Ok(())
+/// # }
+/// ```
+pub struct CurrencySeriesBuilder {
+    currency_code: Option<CurrencyCode>,
+    advanced_processes: Option<AdvancedProcesses>,
+    options: SeriesOptions,
+}
+
+impl CurrencySeriesBuilder {
+    /// creates a builder without any option.
+    pub fn new() -> CurrencySeriesBuilder {
+        CurrencySeriesBuilder {
+            currency_code: None,
+            advanced_processes: None,
+            options: SeriesOptions::new(),
+        }
+    }
+
+    /// sets the currency code.
+    pub fn currency(mut self, currency_code: CurrencyCode) -> CurrencySeriesBuilder {
+        self.currency_code = Some(currency_code);
+        self
+    }
+
+    /// adds forex buying type.
+    pub fn buying(mut self) -> CurrencySeriesBuilder {
+        self.options.exchange_type.buying = true;
+        self
+    }
+
+    /// adds forex selling type.
+    pub fn selling(mut self) -> CurrencySeriesBuilder {
+        self.options.exchange_type.selling = true;
+        self
+    }
+
+    /// adds effective buying type.
+    pub fn effective_buying(mut self) -> CurrencySeriesBuilder {
+        self.options.exchange_type.effective_buying = true;
+        self
+    }
+
+    /// adds effective selling type.
+    pub fn effective_selling(mut self) -> CurrencySeriesBuilder {
+        self.options.exchange_type.effective_selling = true;
+        self
+    }
+
+    /// replaces the selected exchange types with the given exchange type.
+    pub fn exchange_type(mut self, exchange_type: ExchangeType) -> CurrencySeriesBuilder {
+        self.options.exchange_type = exchange_type;
+        self
+    }
+
+    /// sets a single "day-month-year" formatted date such as "13-12-2011".
+    pub fn date(mut self, date: &str) -> CurrencySeriesBuilder {
+        self.options.date_preference = Some(Date::from(date).map(DatePreference::Single));
+        self
+    }
+
+    /// sets a range of "day-month-year" formatted dates.
+    pub fn range(mut self, start_date: &str, end_date: &str) -> CurrencySeriesBuilder {
+        self.options.date_preference = Some(DateRange::from(start_date, end_date).map(DatePreference::Multiple));
+        self
+    }
+
+    /// sets the date preference.
+    pub fn date_preference(mut self, date_preference: DatePreference) -> CurrencySeriesBuilder {
+        self.options.date_preference = Some(Ok(date_preference));
+        self
+    }
+
+    /// requests series with "YTL" suffix.
+    pub fn ytl(mut self) -> CurrencySeriesBuilder {
+        self.options.ytl_mode = true;
+        self
+    }
+
+    /// sets the redenomination preference.
+    pub fn redenomination(mut self, redenomination: Redenomination) -> CurrencySeriesBuilder {
+        self.options.redenomination = redenomination;
+        self
+    }
+
+    /// sets frequency formulas applied by [`get_data_with_formulas`](fn@CurrencySeries::get_data_with_formulas), while
+    /// [`get_data`](fn@CurrencySeries::get_data) keeps giving level values.
+    pub fn advanced_processes(mut self, advanced_processes: AdvancedProcesses) -> CurrencySeriesBuilder {
+        self.advanced_processes = Some(advanced_processes);
+        self
+    }
+
+    /// builds the currency series.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the currency code, the exchange type or the date is not given, a date is
+    /// invalid, ytl mode is given with automatic redenomination or frequency formulas are given with more than one
+    /// exchange type, do not fit exchange rates or cannot be computed across the redenomination.
+    pub fn build(self) -> Result<CurrencySeries, ReturnError> {
+        let currency_code = self.currency_code.ok_or_else(|| ReturnError::MissingOption("currency".to_string()))?;
+
+        let (exchange_type, date_preference, ytl_mode, redenomination) = self.options.validate()?;
+
+        if let Some(advanced_processes) = &self.advanced_processes {
+            exchange_type.validate_single_type()?;
+
+            advanced_processes.validate_for_exchange_rates()?;
+            redenomination.validate(advanced_processes)?;
+        }
+
+        let mut currency_series = CurrencySeries::from(exchange_type, currency_code, date_preference, ytl_mode);
+        currency_series.change_redenomination(redenomination);
+        currency_series.advanced_processes = self.advanced_processes;
+
+        Ok(currency_series)
+    }
+}
+
+impl Default for CurrencySeriesBuilder {
+    fn default() -> CurrencySeriesBuilder {
+        CurrencySeriesBuilder::new()
+    }
+}
+
+
+/// builds a [`MultipleCurrencySeries`](struct@MultipleCurrencySeries) option by option and checks the options in
+/// [`build`](fn@MultipleCurrencySeriesBuilder::build) before any request is made.
+///
+/// Currency codes and exchange types are added to each other.
+///
+/// # Example
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::evds_currency::{CurrencyCode, MultipleCurrencySeries};
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let currency_series = MultipleCurrencySeries::builder()
+///         .currency(CurrencyCode::Usd)
+///         .currency(CurrencyCode::Eur)
+///         .selling()
+///         .date("13-12-2011")
+///         .build()?;
+/// #   Ok(())
+/// # }
+/// ```
+pub struct MultipleCurrencySeriesBuilder {
+    currency_codes: CurrencyCodes,
+    options: SeriesOptions,
+}
+
+impl MultipleCurrencySeriesBuilder {
+    /// creates a builder without any option.
+    pub fn new() -> MultipleCurrencySeriesBuilder {
+        MultipleCurrencySeriesBuilder {
+            currency_codes: CurrencyCodes::default(),
+            options: SeriesOptions::new(),
+        }
+    }
+
+    /// adds the currency code.
+    pub fn currency(mut self, currency_code: CurrencyCode) -> MultipleCurrencySeriesBuilder {
+        self.currency_codes.insert(currency_code);
+        self
+    }
+
+    /// adds the currency codes.
+    pub fn currencies(mut self, currency_codes: CurrencyCodes) -> MultipleCurrencySeriesBuilder {
+        self.currency_codes = self.currency_codes.union(&currency_codes);
+        self
+    }
+
+    /// adds forex buying type.
+    pub fn buying(mut self) -> MultipleCurrencySeriesBuilder {
+        self.options.exchange_type.buying = true;
+        self
+    }
+
+    /// adds forex selling type.
+    pub fn selling(mut self) -> MultipleCurrencySeriesBuilder {
+        self.options.exchange_type.selling = true;
+        self
+    }
+
+    /// adds effective buying type.
+    pub fn effective_buying(mut self) -> MultipleCurrencySeriesBuilder {
+        self.options.exchange_type.effective_buying = true;
+        self
+    }
+
+    /// adds effective selling type.
+    pub fn effective_selling(mut self) -> MultipleCurrencySeriesBuilder {
+        self.options.exchange_type.effective_selling = true;
+        self
+    }
+
+    /// replaces the selected exchange types with the given exchange type.
+    pub fn exchange_type(mut self, exchange_type: ExchangeType) -> MultipleCurrencySeriesBuilder {
+        self.options.exchange_type = exchange_type;
+        self
+    }
+
+    /// sets a single "day-month-year" formatted date such as "13-12-2011".
+    pub fn date(mut self, date: &str) -> MultipleCurrencySeriesBuilder {
+        self.options.date_preference = Some(Date::from(date).map(DatePreference::Single));
+        self
+    }
+
+    /// sets a range of "day-month-year" formatted dates.
+    pub fn range(mut self, start_date: &str, end_date: &str) -> MultipleCurrencySeriesBuilder {
+        self.options.date_preference = Some(DateRange::from(start_date, end_date).map(DatePreference::Multiple));
+        self
+    }
+
+    /// sets the date preference.
+    pub fn date_preference(mut self, date_preference: DatePreference) -> MultipleCurrencySeriesBuilder {
+        self.options.date_preference = Some(Ok(date_preference));
+        self
+    }
+
+    /// requests series with "YTL" suffix.
+    pub fn ytl(mut self) -> MultipleCurrencySeriesBuilder {
+        self.options.ytl_mode = true;
+        self
+    }
+
+    /// sets the redenomination preference.
+    pub fn redenomination(mut self, redenomination: Redenomination) -> MultipleCurrencySeriesBuilder {
+        self.options.redenomination = redenomination;
+        self
+    }
+
+    /// builds the multiple currency series.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if no currency code, exchange type or date is given, a date is invalid or ytl
+    /// mode is given with automatic redenomination.
+    pub fn build(self) -> Result<MultipleCurrencySeries, ReturnError> {
+        if self.currency_codes.is_empty() {
+            return Err(ReturnError::EmptyCurrencyCodes);
+        }
+
+        let (exchange_type, date_preference, ytl_mode, redenomination) = self.options.validate()?;
+
+        let mut currency_series =
+            MultipleCurrencySeries::from(exchange_type, self.currency_codes, date_preference, ytl_mode);
+        currency_series.change_redenomination(redenomination);
+
+        Ok(currency_series)
+    }
+}
+
+impl Default for MultipleCurrencySeriesBuilder {
+    fn default() -> MultipleCurrencySeriesBuilder {
+        MultipleCurrencySeriesBuilder::new()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evds_currency::frequency_formulas::{AggregationType, DataFrequency, Formula};
+
+    #[test]
+    fn should_validate_before_building() {
+        let currency_series = CurrencySeries::builder()
+            .currency(CurrencyCode::Usd)
+            .buying()
+            .selling()
+            .range("13-12-2011", "12-12-2012")
+            .build()
+            .unwrap();
+
+        assert_eq!(vec!["A", "S"], currency_series.exchange_type.make_required_list());
+        assert!(!currency_series.ytl_mode);
+
+        let advanced_processes =
+            || AdvancedProcesses::from(AggregationType::Average, Formula::Level, DataFrequency::Monthly);
+
+        assert!(CurrencySeries::builder().currency(CurrencyCode::Usd).date("13-12-2011").build().is_err());
+        assert_eq!(
+            Some(ReturnError::SingleExchangeTypeExpected),
+            CurrencySeries::builder()
+                .currency(CurrencyCode::Usd)
+                .buying()
+                .selling()
+                .date("13-12-2011")
+                .advanced_processes(advanced_processes())
+                .build()
+                .err()
+        );
+        assert_eq!(
+            Some(ReturnError::MissingOption("currency".to_string())),
+            CurrencySeries::builder().selling().date("13-12-2011").build().err()
+        );
+        assert_eq!(
+            Some(ReturnError::InvalidDate),
+            CurrencySeries::builder().currency(CurrencyCode::Usd).selling().date("2011-12-13").build().err()
+        );
+        assert!(CurrencySeries::builder()
+            .currency(CurrencyCode::Usd)
+            .selling()
+            .date("13-12-2011")
+            .advanced_processes(advanced_processes())
+            .build()
+            .unwrap()
+            .advanced_processes()
+            .is_some());
+        assert!(matches!(
+            CurrencySeries::builder()
+                .currency(CurrencyCode::Usd)
+                .selling()
+                .date("13-12-2011")
+                .advanced_processes(AdvancedProcesses::from(
+                    AggregationType::Cumulative,
+                    Formula::Level,
+                    DataFrequency::Monthly
+                ))
+                .build(),
+            Err(ReturnError::IncompatibleAdvancedProcesses(_))
+        ));
+    }
+
+    #[test]
+    fn should_validate_multiple_currency_series() {
+        let currency_series = MultipleCurrencySeries::builder()
+            .currency(CurrencyCode::Eur)
+            .currencies("USD,EUR".parse().unwrap())
+            .effective_selling()
+            .date("13-12-2011")
+            .build()
+            .unwrap();
+
+        assert_eq!("USD,EUR", currency_series.currency_codes.to_string());
+
+        assert_eq!(
+            Some(ReturnError::EmptyCurrencyCodes),
+            MultipleCurrencySeries::builder().selling().date("13-12-2011").build().err()
+        );
+        assert_eq!(
+            Some(ReturnError::EmptyExchangeType),
+            MultipleCurrencySeries::builder().currency(CurrencyCode::Usd).date("13-12-2011").build().err()
+        );
+        assert!(matches!(
+            MultipleCurrencySeries::builder()
+                .currency(CurrencyCode::Usd)
+                .selling()
+                .date("13-12-2011")
+                .ytl()
+                .redenomination(Redenomination::Automatic { rescale: false })
+                .build(),
+            Err(ReturnError::ConflictingOptions(_))
+        ));
+    }
+}
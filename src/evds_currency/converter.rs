@@ -0,0 +1,568 @@
+use std::fmt;
+
+use super::{CurrencyCode, CurrencyCodes, CurrencyRates, ExchangeType, MultipleCurrencySeries};
+use crate::common::Evds;
+use crate::date::calendar::Day;
+use crate::date::{Date, DatePreference, DateRange};
+use crate::error::ReturnError;
+use crate::evds_basic;
+use crate::response::Observations;
+use crate::traits::MakingList;
+
+
+/// is the number of days before the requested date searched for a published rate.
+///
+/// The central bank does not publish rates on weekends and holidays, the longest of which lasts about a week.
+const LOOK_BACK_DAYS: i64 = 10;
+
+
+/// is a source or a target currency of a [`Conversion`](struct@Conversion), which is either Turkish lira or a currency
+/// quoted by the central bank.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Currency {
+    /// Turkish lira, whose rate is 1 and does not need a request.
+    Try,
+    /// currency quoted against Turkish lira.
+    Code(CurrencyCode),
+}
+
+impl Currency {
+    /// gives the currency code such as "USD" or "TRY".
+    fn code(&self) -> String {
+        match self {
+            Currency::Try => "TRY".to_string(),
+            Currency::Code(currency_code) => currency_code.to_string(),
+        }
+    }
+
+    /// gives the series of the rate of the currency for the side such as "TP.DK.USD.A" if it is not Turkish lira.
+    fn series(&self, side: &str) -> Option<String> {
+        match self {
+            Currency::Try => None,
+            Currency::Code(currency_code) => Some(format!("TP.DK.{}.{}", currency_code.to_string(), side)),
+        }
+    }
+}
+
+impl From<CurrencyCode> for Currency {
+    fn from(currency_code: CurrencyCode) -> Currency {
+        Currency::Code(currency_code)
+    }
+}
+
+
+/// is an exchange rate of a currency against Turkish lira used in a [`Conversion`](struct@Conversion).
+#[derive(Debug, Clone, PartialEq)]
+pub struct UsedRate {
+    /// currency code such as "USD".
+    pub currency_code: String,
+    /// series of the rate such as "TP.DK.USD.A". It is empty for Turkish lira.
+    pub series: String,
+    /// date of the rate in "day-month-year" format, which is the requested date or the latest day before it.
+    pub date: String,
+    /// price of a unit of the currency in Turkish lira.
+    pub rate: f64,
+    /// number of currency units the series is quoted for, such as 100 for "JPY". The quoted value is `rate * unit`.
+    pub unit: u32,
+}
+
+
+/// is the result of [`convert`](fn@convert) with the rates and the dates used for it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Conversion {
+    /// converted amount of the source currency.
+    pub amount: f64,
+    /// amount in the target currency.
+    pub result: f64,
+    /// requested date of the conversion in "day-month-year" format.
+    pub date: String,
+    /// rate of the source currency.
+    pub from_rate: UsedRate,
+    /// rate of the target currency.
+    pub to_rate: UsedRate,
+}
+
+
+/// is a matrix of the exchange rates of currencies against each other and Turkish lira on a date.
+///
+/// The rate in row `i` and column `j` is the price of a unit of the `i`th currency in the `j`th currency. The last
+/// currency is always "TRY". The matrix is printed as a table with [`Display`](trait@fmt::Display).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CrossRates {
+    /// requested date of the rates in "day-month-year" format.
+    pub date: String,
+    /// currency codes of the rows and the columns.
+    pub currency_codes: Vec<String>,
+    /// rates of each currency against each currency.
+    pub rates: Vec<Vec<f64>>,
+    /// rates of the currencies against Turkish lira used to compute the matrix. The rate of Turkish lira is 1 and does
+    /// not have a series.
+    pub used_rates: Vec<UsedRate>,
+}
+
+impl CrossRates {
+    /// gives the price of a unit of the first currency in the second currency if both are in the matrix.
+    pub fn rate(&self, from: &str, to: &str) -> Option<f64> {
+        let index_of = |code: &str| self.currency_codes.iter().position(|element| element.eq_ignore_ascii_case(code));
+
+        Some(self.rates[index_of(from)?][index_of(to)?])
+    }
+}
+
+impl fmt::Display for CrossRates {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:<10}", self.date)?;
+
+        for currency_code in &self.currency_codes {
+            write!(f, " {:>14}", currency_code)?;
+        }
+
+        for (currency_code, rates) in self.currency_codes.iter().zip(&self.rates) {
+            write!(f, "\n{:<10}", currency_code)?;
+
+            for rate in rates {
+                write!(f, " {:>14.6}", rate)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+
+/// converts an amount of a currency to another currency at the rates of the given date.
+///
+/// The amount is converted to Turkish lira with the rate of the source currency and then to the target currency with
+/// the rate of the target currency. Either currency can be Turkish lira, whose rate is 1 and is not requested.
+///
+/// Both legs use the same side of the central bank, namely the forex or the effective buying or selling rates as given
+/// by the exchange type. The result is therefore the cross rate of the central bank on that side, not the amount a
+/// bank would pay, which would use the buying rate for the source currency and the selling rate for the target
+/// currency. When a rate is not published on the date such as weekends and holidays, the latest rate published before
+/// the date is used. The used rates and their dates are given with the result for audit.
+///
+/// # Error
+///
+/// This function returns an error if the exchange type is not a single type, the request fails or a rate is not
+/// published in ten days before the date.
+///
+/// # Example
+///
+/// Follow [`Evds`](crate::common::Evds) for full and detailed implementation of **evds** argument.
+///
+/// ```
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::date::Date;
+///     use tcmb_evds::evds_currency::{CurrencyCode, ExchangeType};
+///     use tcmb_evds::evds_currency::converter::{self, Currency};
+///
+///
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+/// #
+/// #   let date = if let Ok(date) = Date::from("17-12-2011") { date } else { return };
+///     let mut exchange_type = ExchangeType::new();
+///     exchange_type.select_buying_type();
+///
+///     let (from, to) = (Currency::Code(CurrencyCode::Eur), Currency::Code(CurrencyCode::Usd));
+///
+///     let conversion =
+///         match converter::convert(1250.0, from, to, &date, &exchange_type, &evds) {
+///             Err(error) => {
+///                 println!("{}", error.to_string());
+///                 return;
+///             },
+///             Ok(conversion) => conversion,
+///         };
+///
+///     println!(
+///         "{} EUR = {} USD with {} on {} and {} on {}",
+///         conversion.amount, conversion.result,
+///         conversion.from_rate.rate, conversion.from_rate.date,
+///         conversion.to_rate.rate, conversion.to_rate.date,
+///     );
+/// ```
+pub fn convert(
+    amount: f64,
+    from: Currency,
+    to: Currency,
+    date: &Date,
+    exchange_type: &ExchangeType,
+    evds: &Evds,
+) -> Result<Conversion, ReturnError> {
+    convert_with(amount, from, to, date.get_date(), exchange_type, |data_series, date_range| {
+        evds_basic::get_observations(data_series, &DatePreference::Multiple(date_range), evds)
+    })
+}
+
+/// converts the amount with the observations given by the fetch function for the data series and the date range.
+///
+/// The fetch function is not called when both currencies are Turkish lira.
+fn convert_with<F>(
+    amount: f64,
+    from: Currency,
+    to: Currency,
+    date: &str,
+    exchange_type: &ExchangeType,
+    fetch: F,
+) -> Result<Conversion, ReturnError>
+where
+    F: FnOnce(&str, DateRange) -> Result<Observations, ReturnError>,
+{
+    let side = single_side(exchange_type)?;
+    let (day, date_range) = look_back_range(date)?;
+
+    let from_series = from.series(side);
+    let to_series = to.series(side);
+
+    let mut series_set: Vec<&str> = from_series.iter().chain(to_series.iter()).map(String::as_str).collect();
+    series_set.dedup();
+
+    let observations = if series_set.is_empty() {
+        CurrencyRates::from(Observations { series: Vec::new(), rows: Vec::new() })
+    } else {
+        CurrencyRates::from(fetch(&series_set.join("-"), date_range)?)
+    };
+
+    let rate_of = |currency: &Currency, series: &Option<String>| match series {
+        Some(series) => latest_rate(&observations, &currency.code(), series, day),
+        None => Ok(lira_rate(date)),
+    };
+
+    let from_rate = rate_of(&from, &from_series)?;
+    let to_rate = rate_of(&to, &to_series)?;
+
+    Ok(Conversion {
+        amount,
+        result: amount * from_rate.rate / to_rate.rate,
+        date: date.to_string(),
+        from_rate,
+        to_rate,
+    })
+}
+
+/// gives the rate of Turkish lira, which is 1 on every date and does not have a series.
+fn lira_rate(date: &str) -> UsedRate {
+    UsedRate {
+        currency_code: "TRY".to_string(),
+        series: String::new(),
+        date: date.to_string(),
+        rate: 1.0,
+        unit: 1,
+    }
+}
+
+/// gives the cross rates of the selected currencies and Turkish lira at the rates of the given date.
+///
+/// Rates of all currencies are requested at once with a [`MultipleCurrencySeries`](struct@MultipleCurrencySeries)
+/// of the buying or the selling rates as given by the exchange type. When a rate is not published on the date, the
+/// latest rate published before the date is used as in [`convert`](fn@convert).
+///
+/// # Error
+///
+/// This function returns an error if no currency is selected, the exchange type is not a single type, the request fails
+/// or a rate is not published in ten days before the date.
+///
+/// # Example
+///
+/// Follow [`Evds`](crate::common::Evds) for full and detailed implementation of **evds** argument.
+///
+/// ```
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::date::Date;
+///     use tcmb_evds::evds_currency::{CurrencyCodes, ExchangeType};
+///     use tcmb_evds::evds_currency::converter;
+///
+///
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+/// #
+/// #   let date = if let Ok(date) = Date::from("16-12-2011") { date } else { return };
+///     let currency_codes: CurrencyCodes =
+///         if let Ok(currency_codes) = "USD,EUR,GBP".parse() { currency_codes }
+///         else { return };
+///
+///     let cross_rates = match converter::cross_rates(currency_codes, &date, ExchangeType::new(), &evds) {
+///         Err(error) => {
+///             println!("{}", error.to_string());
+///             return;
+///         },
+///         Ok(cross_rates) => cross_rates,
+///     };
+///
+///     println!("{}", cross_rates);
+/// ```
+pub fn cross_rates(
+    currency_codes: CurrencyCodes,
+    date: &Date,
+    exchange_type: ExchangeType,
+    evds: &Evds,
+) -> Result<CrossRates, ReturnError> {
+    let selected_codes: Vec<String> = currency_codes.make_required_list().into_iter().map(String::from).collect();
+    let side = single_side(&exchange_type)?.to_string();
+
+    cross_rates_with(&selected_codes, date.get_date(), &side, |date_range| {
+        let series =
+            MultipleCurrencySeries::from(exchange_type, currency_codes, DatePreference::Multiple(date_range), false);
+
+        Ok(series.get_multiple_rates(evds)?.observations)
+    })
+}
+
+/// computes the cross rates with the observations given by the fetch function for the date range.
+fn cross_rates_with<F>(currency_codes: &[String], date: &str, side: &str, fetch: F) -> Result<CrossRates, ReturnError>
+where
+    F: FnOnce(DateRange) -> Result<Observations, ReturnError>,
+{
+    if currency_codes.is_empty() {
+        return Err(ReturnError::EmptyCurrencyCodes);
+    }
+
+    let (day, date_range) = look_back_range(date)?;
+
+    let observations = CurrencyRates::from(fetch(date_range)?);
+
+    let mut used_rates = currency_codes.iter()
+        .map(|code| latest_rate(&observations, code, &format!("TP.DK.{}.{}", code, side), day))
+        .collect::<Result<Vec<UsedRate>, ReturnError>>()?;
+
+    used_rates.push(lira_rate(date));
+
+    let rates = used_rates.iter()
+        .map(|from| used_rates.iter().map(|to| from.rate / to.rate).collect())
+        .collect();
+
+    Ok(CrossRates {
+        date: date.to_string(),
+        currency_codes: used_rates.iter().map(|used_rate| used_rate.currency_code.clone()).collect(),
+        rates,
+        used_rates,
+    })
+}
+
+/// gives the series suffix of the exchange type such as "A" for buying and "S.EF" for effective selling.
+///
+/// # Error
+///
+/// This function returns an error if the exchange type is empty or has both types.
+fn single_side(exchange_type: &ExchangeType) -> Result<&str, ReturnError> {
+    match exchange_type.make_required_list().as_slice() {
+        [] => Err(ReturnError::EmptyExchangeType),
+        [side] => Ok(side),
+        _ => Err(ReturnError::SingleExchangeTypeExpected),
+    }
+}
+
+/// gives the day of the date and the date range searched for the rates of the date.
+///
+/// # Error
+///
+/// This function returns an error if the date is not in "day-month-year" format.
+fn look_back_range(date: &str) -> Result<(Day, DateRange), ReturnError> {
+    let day = Day::parse(date).ok_or(ReturnError::InvalidDate)?;
+
+    Ok((day, DateRange::from(&day.add_days(-LOOK_BACK_DAYS).format(), date)?))
+}
+
+/// gives the latest rate of the series published until the day as the price of a unit of the currency.
+///
+/// # Error
+///
+/// This function returns an error if the series does not have a rate until the day.
+fn latest_rate(
+    currency_rates: &CurrencyRates,
+    currency_code: &str,
+    series: &str,
+    day: Day,
+) -> Result<UsedRate, ReturnError> {
+    let observations = &currency_rates.observations;
+    let index = observations.series_index(series).ok_or(ReturnError::NoExchangeRate)?;
+    let unit = currency_rates.units[index];
+
+    observations.rows.iter()
+        .filter_map(|row| {
+            let rate_day = Day::parse(&row.date)?;
+
+            match row.values[index] {
+                Some(rate) if rate_day <= day && rate > 0.0 => Some((rate_day, rate)),
+                _ => None,
+            }
+        })
+        .max_by_key(|(rate_day, _)| *rate_day)
+        .map(|(rate_day, rate)| UsedRate {
+            currency_code: currency_code.to_string(),
+            series: series.to_string(),
+            date: rate_day.format(),
+            rate: rate / unit as f64,
+            unit,
+        })
+        .ok_or(ReturnError::NoExchangeRate)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::common::ReturnFormat;
+    use crate::response::Observation;
+
+    const EUR: Currency = Currency::Code(CurrencyCode::Eur);
+    const USD: Currency = Currency::Code(CurrencyCode::Usd);
+    const JPY: Currency = Currency::Code(CurrencyCode::Jpy);
+
+    fn rates() -> Observations {
+        let row = |date: &str, eur: Option<f64>, usd: Option<f64>| {
+            Observation { date: date.to_string(), values: vec![eur, usd] }
+        };
+
+        Observations {
+            series: vec!["TP_DK_EUR_A".to_string(), "TP_DK_USD_A".to_string()],
+            rows: vec![
+                row("15-12-2011", Some(2.40), Some(1.84)),
+                row("16-12-2011", Some(2.45), Some(1.88)),
+                row("17-12-2011", None, None),
+                row("18-12-2011", None, None),
+            ],
+        }
+    }
+
+    #[test]
+    fn should_triangulate_with_weekend_fallback() {
+        let mut exchange_type = ExchangeType::new();
+        exchange_type.select_buying_type();
+
+        let requests = RefCell::new(Vec::new());
+
+        let fetch = |series: &str, date_range: DateRange| {
+            requests.borrow_mut().push((series.to_string(), date_range.get_dates().0.to_string()));
+
+            Ok(rates())
+        };
+
+        let conversion =
+            convert_with(1250.0, EUR, USD, "18-12-2011", &exchange_type, fetch).unwrap();
+
+        assert_eq!(vec![("TP.DK.EUR.A-TP.DK.USD.A".to_string(), "08-12-2011".to_string())], requests.into_inner());
+        assert_eq!("16-12-2011", conversion.from_rate.date);
+        assert_eq!(2.45, conversion.from_rate.rate);
+        assert_eq!(1.88, conversion.to_rate.rate);
+        assert!((conversion.result - 1250.0 * 2.45 / 1.88).abs() < 1e-9);
+    }
+
+    #[test]
+    fn should_convert_from_and_to_lira() {
+        let selling_type = ExchangeType::from(false, true);
+
+        let requests = RefCell::new(Vec::new());
+
+        let fetch = |series: &str, _| {
+            requests.borrow_mut().push(series.to_string());
+
+            Observations::parse_series(
+                r#"{"items":[{"Tarih":"16-12-2011","TP_DK_EUR_S":"2.46"}]}"#,
+                &ReturnFormat::Json,
+                series
+            )
+        };
+
+        let to_lira = convert_with(100.0, EUR, Currency::Try, "17-12-2011", &selling_type, fetch).unwrap();
+
+        assert!((to_lira.result - 246.0).abs() < 1e-9);
+        assert_eq!("16-12-2011", to_lira.from_rate.date);
+        assert_eq!("TRY", to_lira.to_rate.currency_code);
+        assert_eq!(("", 1.0), (to_lira.to_rate.series.as_str(), to_lira.to_rate.rate));
+
+        let from_lira = convert_with(246.0, Currency::Try, EUR, "17-12-2011", &selling_type, fetch).unwrap();
+
+        assert!((from_lira.result - 100.0).abs() < 1e-9);
+        assert_eq!("TP.DK.EUR.S", from_lira.to_rate.series);
+        assert_eq!(vec!["TP.DK.EUR.S".to_string(), "TP.DK.EUR.S".to_string()], requests.into_inner());
+
+        let lira = convert_with(5.0, Currency::Try, Currency::Try, "17-12-2011", &selling_type, |_, _| {
+            Err(ReturnError::UnableToRequest)
+        });
+
+        assert_eq!(Ok(5.0), lira.map(|conversion| conversion.result));
+    }
+
+    #[test]
+    fn should_reject_unusable_requests() {
+        let both_types = ExchangeType::from(true, true);
+        let buying_type = ExchangeType::from(true, false);
+
+        assert_eq!(
+            Err(ReturnError::SingleExchangeTypeExpected),
+            convert_with(1.0, EUR, USD, "18-12-2011", &both_types, |_, _| Ok(rates()))
+        );
+        assert_eq!(
+            Err(ReturnError::NoExchangeRate),
+            convert_with(1.0, EUR, USD, "14-12-2011", &buying_type, |_, _| Ok(rates()))
+        );
+    }
+
+    #[test]
+    fn should_find_no_rate_in_empty_response() {
+        let buying_type = ExchangeType::from(true, false);
+
+        let empty_response = |series: &str, _| {
+            Observations::parse_series(r#"{"totalCount":0,"items":[]}"#, &ReturnFormat::Json, series)
+        };
+
+        let conversion =
+            convert_with(1.0, EUR, USD, "18-12-2011", &buying_type, empty_response);
+
+        assert_eq!(Err(ReturnError::NoExchangeRate), conversion);
+    }
+
+    #[test]
+    fn should_compute_cross_rates_with_lira() {
+        let currency_codes = vec!["EUR".to_string(), "USD".to_string()];
+
+        let cross_rates = cross_rates_with(&currency_codes, "17-12-2011", "A", |date_range| {
+            assert_eq!(("07-12-2011", "17-12-2011"), date_range.get_dates());
+
+            Ok(rates())
+        }).unwrap();
+
+        assert_eq!(vec!["EUR", "USD", "TRY"], cross_rates.currency_codes);
+        assert_eq!(Some(2.45), cross_rates.rate("eur", "try"));
+        assert_eq!(Some(1.0 / 1.88), cross_rates.rate("TRY", "USD"));
+        assert_eq!(Some(2.45 / 1.88), cross_rates.rate("EUR", "USD"));
+        assert_eq!(Some(1.0), cross_rates.rate("USD", "USD"));
+        assert_eq!(None, cross_rates.rate("GBP", "USD"));
+        assert_eq!("16-12-2011", cross_rates.used_rates[1].date);
+
+        let table = cross_rates.to_string();
+
+        assert_eq!(4, table.lines().count());
+        assert!(table.lines().nth(1).unwrap().starts_with("EUR"));
+        assert!(table.contains("2.450000"));
+
+        assert_eq!(Err(ReturnError::EmptyCurrencyCodes), cross_rates_with(&[], "17-12-2011", "A", |_| Ok(rates())));
+    }
+
+    #[test]
+    fn should_convert_per_unit_rates() {
+        let observations = Observations {
+            series: vec!["TP_DK_JPY_S".to_string(), "TP_DK_USD_S".to_string()],
+            rows: vec![Observation { date: "16-12-2011".to_string(), values: vec![Some(2.40), Some(1.88)] }],
+        };
+
+        let exchange_type = ExchangeType::new();
+        let fetch = |_: &str, _| Ok(observations);
+        let conversion =
+            convert_with(1000.0, JPY, USD, "16-12-2011", &exchange_type, fetch).unwrap();
+
+        assert_eq!(100, conversion.from_rate.unit);
+        assert_eq!(0.024, conversion.from_rate.rate);
+        assert!((conversion.result - 1000.0 * 0.024 / 1.88).abs() < 1e-9);
+    }
+}
@@ -1,3 +1,4 @@
+use crate::common::Evds;
 use crate::error::ReturnError;
 #[cfg(feature = "async_mode")]
 use crate::request_async;
@@ -18,8 +19,8 @@ fn check_empty_response(response: &str) -> Result<(), ReturnError> {
 ///
 /// This function is configured for evds currency operations.
 #[cfg(feature = "async_mode")]
-fn make_request_async(url: &str) -> Result<String, ReturnError> {
-    let response = request_async::do_request(&url)?;
+fn make_request_async(url: &str, evds: &Evds) -> Result<String, ReturnError> {
+    let response = request_async::do_request(&url, evds.get_transport())?;
     check_empty_response(&response)?;
     Ok(response)
 }
@@ -28,8 +29,8 @@ fn make_request_async(url: &str) -> Result<String, ReturnError> {
 ///
 /// This function is configured for evds currency operations.
 #[cfg(feature = "sync_mode")]
-fn make_request_sync(url: &str) -> Result<String, ReturnError> {
-    let response = request_sync::do_request(&url)?;
+fn make_request_sync(url: &str, evds: &Evds) -> Result<String, ReturnError> {
+    let response = request_sync::do_request(&url, evds.get_transport())?;
     check_empty_response(&response)?;
     Ok(response)
 }
@@ -39,10 +40,13 @@ fn make_request_sync(url: &str) -> Result<String, ReturnError> {
 /// The most important feature of this function is that the functionality of the function can be changed when 
 /// the crate is compiled according to preferred compiling feature, thanks to the conditional compiling of Rust.
 ///
-/// This function is configured for evds currency operations.
-pub(crate) fn make_request(url: &str) -> Result<String, ReturnError> {
-    #[cfg(feature = "async_mode")]
-    return make_request_async(url);
-    #[cfg(feature = "sync_mode")]
-    return make_request_sync(url);
+/// This function is configured for evds currency operations. Responses are served from the response cache of the
+/// Evds when there is one.
+pub(crate) fn make_request(url: &str, evds: &Evds) -> Result<String, ReturnError> {
+    evds.cached(url, || {
+        #[cfg(feature = "async_mode")]
+        return make_request_async(url, evds);
+        #[cfg(feature = "sync_mode")]
+        return make_request_sync(url, evds);
+    })
 }
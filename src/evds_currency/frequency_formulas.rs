@@ -1,9 +1,13 @@
 use super::*;
+use crate::response::SeriesMetadata;
+
+pub use crate::error::Incompatibility;
 
 /// provides aggregation type options to create an element of frequency formulas.
 ///
 /// This enum is used in [`AdvancedProcesses`](crate::evds_currency::frequency_formulas::AdvancedProcesses) for 
 /// [`get_advanced_data`](crate::evds_currency::CurrencySeries::get_advanced_data) function.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum AggregationType {
     Average,
     Minimum,
@@ -37,6 +41,7 @@ impl traits::MakingUrlFormat for AggregationType {
 ///
 /// This enum is used in [`AdvancedProcesses`](crate::evds_currency::frequency_formulas::AdvancedProcesses) for 
 /// [`get_advanced_data`](crate::evds_currency::CurrencySeries::get_advanced_data) function.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Formula {
     Level, 
     PercentageChange, 
@@ -75,7 +80,9 @@ impl traits::MakingUrlFormat for Formula {
 /// provides data frequency options to create an element of frequency formulas.
 ///
 /// This struct is used for [`AdvancedProcesses`](crate::evds_currency::frequency_formulas::AdvancedProcesses) for 
-/// [`get_advanced_data`](crate::evds_currency::CurrencySeries::get_advanced_data) function.
+/// [`get_advanced_data`](crate::evds_currency::CurrencySeries::get_advanced_data) function. It also declares the
+/// frequency of a series in [`SeriesMetadata`](struct@crate::response::SeriesMetadata).
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum DataFrequency {
     Daily, 
     Business, 
@@ -102,6 +109,42 @@ impl ToString for DataFrequency {
     }
 }
 
+impl DataFrequency {
+    /// converts a frequency given in series metadata, either as the "FREQUENCY_STR" value of EVDS such as "İŞ GÜNÜ"
+    /// and "AYLIK" or as the frequency number such as "2" and "5".
+    ///
+    /// Other values are not guessed and give `None`, so that the frequency formulas of the series are not checked.
+    pub(crate) fn from_metadata(frequency: &str) -> Option<DataFrequency> {
+        let data_frequency = match frequency.trim() {
+            "1" | "GÜNLÜK" => DataFrequency::Daily,
+            "2" | "İŞ GÜNÜ" => DataFrequency::Business,
+            "3" | "HAFTALIK" => DataFrequency::WeeklyFriday,
+            "4" | "AYDA 2 KEZ" => DataFrequency::TwiceMonthly,
+            "5" | "AYLIK" => DataFrequency::Monthly,
+            "6" | "3 AYLIK" => DataFrequency::Quarterly,
+            "7" | "6 AYLIK" => DataFrequency::SemiAnnual,
+            "8" | "YILLIK" => DataFrequency::Annual,
+            _ => return None,
+        };
+
+        Some(data_frequency)
+    }
+
+    /// gives the order of the frequency from the most frequent to the least. Daily and business day frequencies have
+    /// the same order, since EVDS gives the days of a business day series for both of them.
+    pub(crate) fn order(self) -> u32 {
+        match self {
+            DataFrequency::Daily | DataFrequency::Business => 0,
+            DataFrequency::WeeklyFriday => 1,
+            DataFrequency::TwiceMonthly => 2,
+            DataFrequency::Monthly => 3,
+            DataFrequency::Quarterly => 4,
+            DataFrequency::SemiAnnual => 5,
+            DataFrequency::Annual => 6,
+        }
+    }
+}
+
 impl traits::MakingUrlFormat for DataFrequency {
     fn generate_url_format(&self) -> String {
         format!("frequency={}", self.to_string())
@@ -112,7 +155,8 @@ impl traits::MakingUrlFormat for DataFrequency {
 /// contains the elements of frequency formulas.
 ///
 /// This struct is used as an argument for 
-/// [`get_advanced_data`](crate::evds_currency::CurrencySeries::get_advanced_data) function.
+/// [`get_advanced_data`](crate::evds_currency::CurrencySeries::get_advanced_data) function, which validates it
+/// against exchange rates before the request.
 pub struct AdvancedProcesses {
     pub aggregation_type: AggregationType,
     pub formula: Formula,
@@ -144,6 +188,85 @@ impl AdvancedProcesses {
         }
     }
 
+    /// checks that the formula fits the data frequency and the data frequency is not more frequent than the frequency
+    /// of the series, if it is known.
+    ///
+    /// # Error
+    ///
+    /// This function returns [`ReturnError::IncompatibleAdvancedProcesses`](crate::error::ReturnError) with the
+    /// [`Incompatibility`](enum@Incompatibility) found.
+    ///
+    /// # Example
+    /// ```
+    ///     use tcmb_evds::error::ReturnError;
+    ///     use tcmb_evds::evds_currency::frequency_formulas::*;
+    ///
+    ///     let formula = Formula::YearToYearPercentChange;
+    ///     let advanced_processes = AdvancedProcesses::from(AggregationType::Average, formula, DataFrequency::Daily);
+    ///
+    ///     assert_eq!(
+    ///         Err(ReturnError::IncompatibleAdvancedProcesses(Incompatibility::YearToYearFormula {
+    ///             formula: Formula::YearToYearPercentChange,
+    ///             data_frequency: DataFrequency::Daily,
+    ///         })),
+    ///         advanced_processes.validate(Some(DataFrequency::Business))
+    ///     );
+    /// ```
+    pub fn validate(&self, series_frequency: Option<DataFrequency>) -> Result<(), ReturnError> {
+        let incompatible = |incompatibility| Err(ReturnError::IncompatibleAdvancedProcesses(incompatibility));
+
+        if let Some(series_frequency) = series_frequency {
+            if self.data_frequency.order() < series_frequency.order() {
+                return incompatible(Incompatibility::FinerThanSeries {
+                    series_frequency,
+                    data_frequency: self.data_frequency,
+                });
+            }
+        }
+
+        let is_year_to_year = matches!(self.formula, Formula::YearToYearPercentChange | Formula::YearToYearDifferences);
+
+        if is_year_to_year && self.data_frequency.order() < DataFrequency::TwiceMonthly.order() {
+            return incompatible(Incompatibility::YearToYearFormula {
+                formula: self.formula,
+                data_frequency: self.data_frequency,
+            });
+        }
+
+        Ok(())
+    }
+
+    /// checks the frequency formulas against the series whose metadata is given by
+    /// [`get_series_metadata`](fn@crate::evds_basic::get_series_metadata).
+    ///
+    /// [`evds_basic::get_advanced_data`](fn@crate::evds_basic::get_advanced_data) checks each requested series in this
+    /// way before the request.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`validate`](fn@AdvancedProcesses::validate).
+    pub fn validate_for_series(&self, metadata: &SeriesMetadata) -> Result<(), ReturnError> {
+        self.validate(metadata.data_frequency())
+    }
+
+    /// checks the frequency formulas against exchange rates, which are published on business days and cannot be
+    /// summed.
+    ///
+    /// Each exchange rate series of the central bank is declared as a business day series in its metadata, therefore
+    /// the business day frequency is used without requesting the metadata.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`validate`](fn@AdvancedProcesses::validate) and an error if the
+    /// aggregation is cumulative or the formula is moving sum.
+    pub fn validate_for_exchange_rates(&self) -> Result<(), ReturnError> {
+        if self.aggregation_type == AggregationType::Cumulative || self.formula == Formula::MovingSum {
+            return Err(ReturnError::IncompatibleAdvancedProcesses(Incompatibility::SumOfRates));
+        }
+
+        self.validate(Some(DataFrequency::Business))
+    }
+
     pub(crate) fn get_aggregation_type_as_url_format(&self) -> String {
         self.aggregation_type.generate_url_format()
     }
@@ -156,3 +279,45 @@ impl AdvancedProcesses {
         self.data_frequency.generate_url_format()
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_reject_incompatible_combinations() {
+        let advanced_processes = |aggregation_type, formula, data_frequency| {
+            AdvancedProcesses::from(aggregation_type, formula, data_frequency)
+        };
+
+        assert_eq!(
+            Ok(()),
+            advanced_processes(AggregationType::End, Formula::YearToYearPercentChange, DataFrequency::Monthly)
+                .validate_for_exchange_rates()
+        );
+        assert_eq!(
+            Err(ReturnError::IncompatibleAdvancedProcesses(Incompatibility::SumOfRates)),
+            advanced_processes(AggregationType::Cumulative, Formula::Level, DataFrequency::Monthly)
+                .validate_for_exchange_rates()
+        );
+        assert_eq!(
+            Err(ReturnError::IncompatibleAdvancedProcesses(Incompatibility::FinerThanSeries {
+                series_frequency: DataFrequency::Monthly,
+                data_frequency: DataFrequency::WeeklyFriday,
+            })),
+            advanced_processes(AggregationType::Average, Formula::Level, DataFrequency::WeeklyFriday)
+                .validate(Some(DataFrequency::Monthly))
+        );
+        assert_eq!(
+            Ok(()),
+            advanced_processes(AggregationType::Average, Formula::Level, DataFrequency::Daily)
+                .validate(Some(DataFrequency::Business))
+        );
+        assert!(
+            advanced_processes(AggregationType::Average, Formula::YearToYearDifferences, DataFrequency::WeeklyFriday)
+                .validate(None)
+                .is_err()
+        );
+    }
+}
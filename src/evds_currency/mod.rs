@@ -1,26 +1,46 @@
 /// contains advanced currency operation requirements that are aggregation type, formula and data frequency included in
 /// [`AdvancedProcesses`](struct@frequency_formulas::AdvancedProcesses).
 pub mod frequency_formulas;
+/// converts amounts between currencies through Turkish lira and gives cross rates with the exchange rates of the
+/// central bank.
+pub mod converter;
 
 /// provides specific make request function for currency operations.
 mod currency;
+/// contains the typed form of currency responses with quotation units.
+mod rates;
+/// requests the series of old Turkish lira and Turkish lira for each side of the redenomination in 2005.
+mod redenomination;
+/// builds currency series option by option with checks before any request.
+mod builder;
 
 
 use self::frequency_formulas::*;
 
+pub use self::rates::CurrencyRates;
+pub use self::redenomination::Redenomination;
+pub use self::builder::{CurrencySeriesBuilder, MultipleCurrencySeriesBuilder};
+
 use crate::common;
 use crate::error::ReturnError;
 use crate::date::DatePreference;
+use crate::series_split;
 use crate::traits::{self, MakingList, MakingUrlFormat, EnumSpecific, ConvertingToRustEnum};
 
 
-/// contains exchange types, which are selling and buying, to configure currency request.
+/// contains exchange types, which are forex selling and buying and effective (banknote) selling and buying, to
+/// configure currency request.
+///
+/// Forex buying and selling series are such as "TP.DK.USD.A" and "TP.DK.USD.S", while effective buying and selling
+/// series are such as "TP.DK.USD.A.EF" and "TP.DK.USD.S.EF".
 /// 
 /// This structure is required for all *evds_currency* functions.
 #[derive(Debug)]
 pub struct ExchangeType {
     buying: bool,
     selling: bool,
+    effective_buying: bool,
+    effective_selling: bool,
 }
 
 impl ExchangeType {
@@ -28,50 +48,70 @@ impl ExchangeType {
     /// 
     /// Default type is selling.
     pub fn new() -> ExchangeType {
-        ExchangeType {
-            buying: false,
-            selling: true,
-        }
+        ExchangeType::from(false, true)
     }
 
-    /// creates specified exchange type variable.
+    /// creates specified forex exchange type variable.
     pub fn from(buying: bool, selling: bool) -> ExchangeType {
+        ExchangeType::from_all(buying, selling, false, false)
+    }
+
+    /// creates specified forex and effective exchange type variable.
+    pub fn from_all(buying: bool, selling: bool, effective_buying: bool, effective_selling: bool) -> ExchangeType {
         ExchangeType {
             buying,
-            selling
+            selling,
+            effective_buying,
+            effective_selling,
         }
     }
 
-    /// makes buying type true and selling type false.
+    /// makes buying type true and the other types false.
     pub fn select_buying_type(&mut self) {
-        self.buying = true;
-        self.selling = false;
+        *self = ExchangeType::from(true, false);
     }
 
-    /// makes selling type true and buying type false.
+    /// makes selling type true and the other types false.
     pub fn select_selling_type(&mut self) {
-        self.buying = false;
-        self.selling = true;
+        *self = ExchangeType::from(false, true);
     }
 
-    /// makes both type true.
+    /// makes both forex types true and effective types false.
     pub fn select_both_types(&mut self) {
-        self.buying = true;
-        self.selling = true;
+        *self = ExchangeType::from(true, true);
     }
 
-    pub(crate) fn is_selling_type(&self) -> bool {
-        self.selling
+    /// makes effective buying type true and the other types false.
+    pub fn select_effective_buying_type(&mut self) {
+        *self = ExchangeType::from_all(false, false, true, false);
     }
 
-    pub(crate) fn is_buying_type(&self) -> bool {
-        self.buying
+    /// makes effective selling type true and the other types false.
+    pub fn select_effective_selling_type(&mut self) {
+        *self = ExchangeType::from_all(false, false, false, true);
     }
 
-    pub(crate) fn are_both_types(&self) -> bool {
-        if self.is_selling_type() && self.is_buying_type() { return true }
-        
-        false
+    /// makes both effective types true and forex types false.
+    pub fn select_effective_types(&mut self) {
+        *self = ExchangeType::from_all(false, false, true, true);
+    }
+
+    /// makes all forex and effective types true.
+    pub fn select_all_types(&mut self) {
+        *self = ExchangeType::from_all(true, true, true, true);
+    }
+
+    /// checks that a single type is selected.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if no type or more than one type is selected.
+    pub(crate) fn validate_single_type(&self) -> Result<(), ReturnError> {
+        match self.make_required_list().len() {
+            0 => Err(ReturnError::EmptyExchangeType),
+            1 => Ok(()),
+            _ => Err(ReturnError::SingleExchangeTypeExpected),
+        }
     }
 }
 
@@ -85,6 +125,12 @@ impl traits::MakingList for ExchangeType {
         if self.selling {
             exchange_type_list.push("S");
         }
+        if self.effective_buying {
+            exchange_type_list.push("A.EF");
+        }
+        if self.effective_selling {
+            exchange_type_list.push("S.EF");
+        }
 
         exchange_type_list
     }
@@ -92,7 +138,7 @@ impl traits::MakingList for ExchangeType {
 
 
 /// supplies currency code option to the functions making single currency request.
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum CurrencyCode {
     Usd,
     Aud,
@@ -113,6 +159,11 @@ pub enum CurrencyCode {
     Cny,
     Pkr,
     Qar,
+    Krw,
+    Azn,
+    Aed,
+    Kzt,
+    Xdr,
 }
 
 impl ToString for CurrencyCode {
@@ -137,6 +188,11 @@ impl ToString for CurrencyCode {
             &Self::Cny => String::from("CNY"),
             &Self::Pkr => String::from("PKR"),
             &Self::Qar => String::from("QAR"),
+            &Self::Krw => String::from("KRW"),
+            &Self::Azn => String::from("AZN"),
+            &Self::Aed => String::from("AED"),
+            &Self::Kzt => String::from("KZT"),
+            &Self::Xdr => String::from("XDR"),
         }
     }
 }
@@ -146,152 +202,200 @@ impl EnumSpecific for CurrencyCode {}
 
 // This implementation is used for C FFI operations.
 impl ConvertingToRustEnum<CurrencyCode> for &str {
-    fn convert(&self) -> CurrencyCode {
-        let lower_case_currency = &*self.to_ascii_lowercase();
-
-        return match lower_case_currency {
-            "usd" => CurrencyCode::Usd,
-            "aud" => CurrencyCode::Aud,
-            "dkk" => CurrencyCode::Dkk,
-            "eur" => CurrencyCode::Eur,
-            "gbp" => CurrencyCode::Gbp,
-            "chf" => CurrencyCode::Chf,
-            "sek" => CurrencyCode::Sek,
-            "cad" => CurrencyCode::Cad,
-            "kwd" => CurrencyCode::Kwd,
-            "nok" => CurrencyCode::Nok,
-            "sar" => CurrencyCode::Sar,
-            "jpy" => CurrencyCode::Jpy,
-            "bgn" => CurrencyCode::Bgn,
-            "ron" => CurrencyCode::Ron,
-            "rub" => CurrencyCode::Rub,
-            "irr" => CurrencyCode::Irr,
-            "cny" => CurrencyCode::Cny,
-            "pkr" => CurrencyCode::Pkr,
-            _     => CurrencyCode::Qar,
+    fn convert(&self) -> Result<CurrencyCode, ReturnError> {
+        self.parse()
+    }
+}
+
+impl std::str::FromStr for CurrencyCode {
+    type Err = ReturnError;
+
+    /// parses a currency code such as "USD" regardless of its case.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the code is not one of the currency codes.
+    fn from_str(code: &str) -> Result<CurrencyCode, ReturnError> {
+        let code = code.trim();
+
+        CurrencyCode::ALL.iter()
+            .find(|currency_code| currency_code.to_string().eq_ignore_ascii_case(code))
+            .copied()
+            .ok_or(ReturnError::InvalidCurrencyCode)
+    }
+}
+
+impl std::convert::TryFrom<&str> for CurrencyCode {
+    type Error = ReturnError;
+
+    fn try_from(code: &str) -> Result<CurrencyCode, ReturnError> {
+        code.parse()
+    }
+}
+
+impl CurrencyCode {
+    /// contains all currency codes in the order of the central bank.
+    pub const ALL: [CurrencyCode; 24] = [
+        CurrencyCode::Usd,
+        CurrencyCode::Aud,
+        CurrencyCode::Dkk,
+        CurrencyCode::Eur,
+        CurrencyCode::Gbp,
+        CurrencyCode::Chf,
+        CurrencyCode::Sek,
+        CurrencyCode::Cad,
+        CurrencyCode::Kwd,
+        CurrencyCode::Nok,
+        CurrencyCode::Sar,
+        CurrencyCode::Jpy,
+        CurrencyCode::Bgn,
+        CurrencyCode::Ron,
+        CurrencyCode::Rub,
+        CurrencyCode::Irr,
+        CurrencyCode::Cny,
+        CurrencyCode::Pkr,
+        CurrencyCode::Qar,
+        CurrencyCode::Krw,
+        CurrencyCode::Azn,
+        CurrencyCode::Aed,
+        CurrencyCode::Kzt,
+        CurrencyCode::Xdr,
+    ];
+
+    /// gives ISO 4217 details, names and the quotation unit of the currency.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::error::Error;
+    ///     use tcmb_evds::evds_currency::CurrencyCode;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///     let currency_code: CurrencyCode = "jpy".parse()?;
+    ///
+    ///     let metadata = currency_code.metadata();
+    ///
+    ///     assert_eq!(392, metadata.numeric_code);
+    ///     assert_eq!(100, metadata.unit);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn metadata(&self) -> CurrencyMetadata {
+        match self {
+            Self::Usd => CurrencyMetadata::from("USD", 840, Some(2), "ABD Doları", "US Dollar", 1),
+            Self::Aud => CurrencyMetadata::from("AUD", 36, Some(2), "Avustralya Doları", "Australian Dollar", 1),
+            Self::Dkk => CurrencyMetadata::from("DKK", 208, Some(2), "Danimarka Kronu", "Danish Krone", 1),
+            Self::Eur => CurrencyMetadata::from("EUR", 978, Some(2), "Euro", "Euro", 1),
+            Self::Gbp => CurrencyMetadata::from("GBP", 826, Some(2), "İngiliz Sterlini", "Pound Sterling", 1),
+            Self::Chf => CurrencyMetadata::from("CHF", 756, Some(2), "İsviçre Frangı", "Swiss Franc", 1),
+            Self::Sek => CurrencyMetadata::from("SEK", 752, Some(2), "İsveç Kronu", "Swedish Krona", 1),
+            Self::Cad => CurrencyMetadata::from("CAD", 124, Some(2), "Kanada Doları", "Canadian Dollar", 1),
+            Self::Kwd => CurrencyMetadata::from("KWD", 414, Some(3), "Kuveyt Dinarı", "Kuwaiti Dinar", 1),
+            Self::Nok => CurrencyMetadata::from("NOK", 578, Some(2), "Norveç Kronu", "Norwegian Krone", 1),
+            Self::Sar => CurrencyMetadata::from("SAR", 682, Some(2), "Suudi Arabistan Riyali", "Saudi Riyal", 1),
+            Self::Jpy => CurrencyMetadata::from("JPY", 392, Some(0), "Japon Yeni", "Japanese Yen", 100),
+            Self::Bgn => CurrencyMetadata::from("BGN", 975, Some(2), "Bulgar Levası", "Bulgarian Lev", 1),
+            Self::Ron => CurrencyMetadata::from("RON", 946, Some(2), "Rumen Leyi", "Romanian Leu", 1),
+            Self::Rub => CurrencyMetadata::from("RUB", 643, Some(2), "Rus Rublesi", "Russian Rouble", 1),
+            Self::Irr => CurrencyMetadata::from("IRR", 364, Some(2), "İran Riyali", "Iranian Rial", 100),
+            Self::Cny => CurrencyMetadata::from("CNY", 156, Some(2), "Çin Yuanı", "Chinese Renminbi", 1),
+            Self::Pkr => CurrencyMetadata::from("PKR", 586, Some(2), "Pakistan Rupisi", "Pakistani Rupee", 1),
+            Self::Qar => CurrencyMetadata::from("QAR", 634, Some(2), "Katar Riyali", "Qatari Rial", 1),
+            Self::Krw => CurrencyMetadata::from("KRW", 410, Some(0), "Güney Kore Wonu", "South Korean Won", 1),
+            Self::Azn => {
+                CurrencyMetadata::from("AZN", 944, Some(2), "Azerbaycan Yeni Manatı", "Azerbaijani New Manat", 1)
+            },
+            Self::Aed => {
+                CurrencyMetadata::from("AED", 784, Some(2), "Birleşik Arap Emirlikleri Dirhemi", "UAE Dirham", 1)
+            },
+            Self::Kzt => CurrencyMetadata::from("KZT", 398, Some(2), "Kazakistan Tengesi", "Kazakhstani Tenge", 1),
+            Self::Xdr => CurrencyMetadata::from("XDR", 960, None, "Özel Çekme Hakkı", "Special Drawing Right", 1),
         }
     }
 }
 
 
+/// contains ISO 4217 details, names and the quotation unit of a [`CurrencyCode`](enum@CurrencyCode).
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurrencyMetadata {
+    /// alphabetic ISO 4217 code such as "USD".
+    pub code: &'static str,
+    /// numeric ISO 4217 code such as 840 for "USD".
+    pub numeric_code: u16,
+    /// number of digits after the decimal separator. Special drawing right does not have minor units.
+    pub minor_units: Option<u8>,
+    /// name in Turkish.
+    pub name: &'static str,
+    /// name in English.
+    pub name_eng: &'static str,
+    /// number of currency units the central bank quotes its rates for, such as 100 for "JPY" and "IRR".
+    ///
+    /// Units are fixed as listed in the indicative exchange rates of the central bank at
+    /// <https://www.tcmb.gov.tr/kurlar/today.xml> and are the same for every date of a series.
+    pub unit: u32,
+}
+
+impl CurrencyMetadata {
+    const fn from(
+        code: &'static str,
+        numeric_code: u16,
+        minor_units: Option<u8>,
+        name: &'static str,
+        name_eng: &'static str,
+        unit: u32,
+    ) -> CurrencyMetadata {
+        CurrencyMetadata { code, numeric_code, minor_units, name, name_eng, unit }
+    }
+}
+
+
 /// supplies currency codes to generate multiple currency series for 
 /// [`MultipleCurrencySeries`](struct@MultipleCurrencySeries).
 ///
-/// When a currency is decided to be used, its state should be true. 
+/// The struct is a set of [`CurrencyCode`](enum@CurrencyCode) values, which are iterated in the order of the central
+/// bank as in [`CurrencyCode::ALL`](const@CurrencyCode::ALL) regardless of the order they are inserted.
 ///
-/// The struct designed to select more than one currency code by making them true manually. It is also possible to 
-/// select a currency code.
-///
-/// Default of CurrencyCodes makes all of the elements false.
+/// Default of CurrencyCodes does not contain any currency code.
 ///
 /// # Usage
 /// ```
-/// #   use tcmb_evds::evds_currency::CurrencyCodes;
-/// #
-///     let currency_codes_1 = CurrencyCodes {
-///         usd: true,
-///         aud: true,
-///         pkr: true,
-///         ..Default::default()
-///     };
-///     
-///     let currency_codes_2 = CurrencyCodes {
-///         gbp: true,
-///         ..Default::default()
-///     };
+/// # use std::error::Error;
+///     use tcmb_evds::evds_currency::{CurrencyCode, CurrencyCodes};
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let currency_codes_1: CurrencyCodes = "USD,EUR,GBP".parse()?;
+///
+///     let currency_codes_2: CurrencyCodes = vec![CurrencyCode::Gbp, CurrencyCode::Usd].into_iter().collect();
 ///
-///     let currency_codes_3 = CurrencyCodes {
-///         sar: true,
-///         qar: true,
-///         ..Default::default()
-///     };
+///     let mut currency_codes_3 = CurrencyCodes::default();
+///     currency_codes_3.insert(CurrencyCode::Sar);
+///     currency_codes_3.insert(CurrencyCode::Qar);
+///
+///     assert_eq!(currency_codes_2, currency_codes_1.intersection(&currency_codes_2));
+///     assert_eq!("USD,SAR,QAR", currency_codes_3.union(&CurrencyCodes::new()).to_string());
+/// #   Ok(())
+/// # }
 /// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
 pub struct CurrencyCodes {
-    pub usd: bool,
-    pub aud: bool,
-    pub dkk: bool,
-    pub eur: bool,
-    pub gbp: bool,
-    pub chf: bool,
-    pub sek: bool,
-    pub cad: bool,
-    pub kwd: bool,
-    pub nok: bool,
-    pub sar: bool,
-    pub jpy: bool,
-    pub bgn: bool,
-    pub ron: bool,
-    pub rub: bool,
-    pub irr: bool,
-    pub cny: bool,
-    pub pkr: bool,
-    pub qar: bool,
-}
-
-impl Default for CurrencyCodes {
-    fn default() -> CurrencyCodes {
-        CurrencyCodes {
-            usd: false,
-            aud: false,
-            dkk: false,
-            eur: false,
-            gbp: false,
-            chf: false,
-            sek: false,
-            cad: false,
-            kwd: false,
-            nok: false,
-            sar: false,
-            jpy: false,
-            bgn: false,
-            ron: false,
-            rub: false,
-            irr: false,
-            cny: false,
-            pkr: false,
-            qar: false,
-        }
-    }
+    /// has a bit for each currency code at its position in [`CurrencyCode::ALL`](const@CurrencyCode::ALL).
+    selected: u32,
 }
 
 impl CurrencyCodes {
     /// "usd" is used as default currency code. 
     pub fn new() -> CurrencyCodes {
-        CurrencyCodes {
-            usd: true,
-            ..Default::default()
-        }
+        std::iter::once(CurrencyCode::Usd).collect()
     }
 
     /// "usd" is used as default currency code.
     pub fn reset(&mut self) {
-        *self = CurrencyCodes{
-            usd: true,
-            ..Default::default()
-        };
+        *self = CurrencyCodes::new();
     }
 
     /// makes all currency codes ON.
     pub fn include_all(&mut self) {
-        self.usd = true;
-        self.aud = true;
-        self.dkk = true;
-        self.eur = true;
-        self.gbp = true;
-        self.chf = true;
-        self.sek = true;
-        self.cad = true;
-        self.kwd = true;
-        self.nok = true;
-        self.sar = true;
-        self.jpy = true;
-        self.bgn = true;
-        self.ron = true;
-        self.rub = true;
-        self.irr = true;
-        self.cny = true;
-        self.pkr = true;
-        self.qar = true;
+        *self = CurrencyCode::ALL.iter().copied().collect();
     }
 
     /// makes all currency codes OFF.
@@ -301,56 +405,121 @@ impl CurrencyCodes {
 
     /// checks the situation all currency codes are OFF.
     pub fn is_all_excluded(&self) -> bool {
-        if self.usd { return false }
-        if self.aud { return false }
-        if self.dkk { return false }
-        if self.eur { return false }
-        if self.gbp { return false }
-        if self.chf { return false }
-        if self.sek { return false }
-        if self.cad { return false }
-        if self.kwd { return false }
-        if self.nok { return false }
-        if self.sar { return false }
-        if self.jpy { return false }
-        if self.bgn { return false }
-        if self.ron { return false }
-        if self.rub { return false }
-        if self.irr { return false }
-        if self.cny { return false }
-        if self.pkr { return false }
-        if self.qar { return false }
-        
-        true
+        self.is_empty()
+    }
+
+    /// gives the bit of the currency code.
+    fn bit(currency_code: CurrencyCode) -> u32 {
+        1 << currency_code as u32
+    }
+
+    /// adds the currency code and gives whether it was not contained.
+    pub fn insert(&mut self, currency_code: CurrencyCode) -> bool {
+        let is_new = !self.contains(currency_code);
+        self.selected |= CurrencyCodes::bit(currency_code);
+
+        is_new
+    }
+
+    /// removes the currency code and gives whether it was contained.
+    pub fn remove(&mut self, currency_code: CurrencyCode) -> bool {
+        let was_contained = self.contains(currency_code);
+        self.selected &= !CurrencyCodes::bit(currency_code);
+
+        was_contained
+    }
+
+    /// checks whether the currency code is contained.
+    pub fn contains(&self, currency_code: CurrencyCode) -> bool {
+        self.selected & CurrencyCodes::bit(currency_code) != 0
+    }
+
+    /// gives the number of contained currency codes.
+    pub fn len(&self) -> usize {
+        self.selected.count_ones() as usize
+    }
+
+    /// checks whether no currency code is contained.
+    pub fn is_empty(&self) -> bool {
+        self.selected == 0
+    }
+
+    /// gives the currency codes contained in either of the sets.
+    pub fn union(&self, other: &CurrencyCodes) -> CurrencyCodes {
+        CurrencyCodes { selected: self.selected | other.selected }
+    }
+
+    /// gives the currency codes contained in both of the sets.
+    pub fn intersection(&self, other: &CurrencyCodes) -> CurrencyCodes {
+        CurrencyCodes { selected: self.selected & other.selected }
+    }
+
+    /// gives the currency codes contained in this set but not in the other.
+    pub fn difference(&self, other: &CurrencyCodes) -> CurrencyCodes {
+        CurrencyCodes { selected: self.selected & !other.selected }
+    }
+
+    /// iterates the contained currency codes in the order of the central bank.
+    pub fn iter(&self) -> impl Iterator<Item = CurrencyCode> + '_ {
+        CurrencyCode::ALL.iter().copied().filter(move |currency_code| self.contains(*currency_code))
+    }
+}
+
+impl std::iter::FromIterator<CurrencyCode> for CurrencyCodes {
+    fn from_iter<I: IntoIterator<Item = CurrencyCode>>(currency_codes: I) -> CurrencyCodes {
+        let mut set = CurrencyCodes::default();
+        set.extend(currency_codes);
+
+        set
+    }
+}
+
+impl Extend<CurrencyCode> for CurrencyCodes {
+    fn extend<I: IntoIterator<Item = CurrencyCode>>(&mut self, currency_codes: I) {
+        for currency_code in currency_codes {
+            self.insert(currency_code);
+        }
+    }
+}
+
+impl IntoIterator for CurrencyCodes {
+    type Item = CurrencyCode;
+    type IntoIter = std::vec::IntoIter<CurrencyCode>;
+
+    fn into_iter(self) -> std::vec::IntoIter<CurrencyCode> {
+        self.iter().collect::<Vec<CurrencyCode>>().into_iter()
+    }
+}
+
+impl std::str::FromStr for CurrencyCodes {
+    type Err = ReturnError;
+
+    /// parses a comma separated list of currency codes such as "USD,EUR,GBP" regardless of their cases.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if one of the codes is not one of the currency codes.
+    fn from_str(currency_codes: &str) -> Result<CurrencyCodes, ReturnError> {
+        currency_codes.split(',')
+            .filter(|code| !code.trim().is_empty())
+            .map(str::parse::<CurrencyCode>)
+            .collect()
+    }
+}
+
+impl std::fmt::Display for CurrencyCodes {
+    /// writes the currency codes separated by commas such as "USD,EUR,GBP".
+    fn fmt(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        let codes: Vec<&str> = self.make_required_list();
+
+        write!(formatter, "{}", codes.join(","))
     }
 }
 
 impl traits::MakingList for CurrencyCodes {
     /// makes a list of used currency codes.
     fn make_required_list(&self) -> Vec<&str> {
-        let mut currency_codes = Vec::new();
-        
-        if self.usd { currency_codes.push("USD"); }
-        if self.aud { currency_codes.push("AUD"); }
-        if self.dkk { currency_codes.push("DKK"); }
-        if self.eur { currency_codes.push("EUR"); }
-        if self.gbp { currency_codes.push("GBP"); }
-        if self.chf { currency_codes.push("CHF"); }
-        if self.sek { currency_codes.push("SEK"); }
-        if self.cad { currency_codes.push("CAD"); }
-        if self.kwd { currency_codes.push("KWD"); }
-        if self.nok { currency_codes.push("NOK"); }
-        if self.sar { currency_codes.push("SAR"); }
-        if self.jpy { currency_codes.push("JPY"); }
-        if self.bgn { currency_codes.push("BGN"); }
-        if self.ron { currency_codes.push("RON"); }
-        if self.rub { currency_codes.push("RUB"); }
-        if self.irr { currency_codes.push("IRR"); }
-        if self.cny { currency_codes.push("CNY"); }
-        if self.pkr { currency_codes.push("PKR"); }
-        if self.qar { currency_codes.push("QAR"); }
-
-        currency_codes
+        self.iter().map(|currency_code| currency_code.metadata().code).collect()
     }
 }
 
@@ -361,20 +530,33 @@ impl traits::MakingList for CurrencyCodes {
 ///
 /// This struct accepts both Single and Multiple date options.
 ///
-/// It is recommended CurrencySeries variable to be created via [`from`](fn@CurrencySeries::from).
+/// It is recommended CurrencySeries variable to be created via [`from`](fn@CurrencySeries::from) or
+/// [`builder`](fn@CurrencySeries::builder), which checks the options before any request.
+///
+/// Ytl mode is ignored when [`redenomination`](enum@Redenomination) is automatic, which chooses the series for each
+/// side of 01-01-2005.
+///
+/// The redenomination preference is read by [`redenomination`](fn@CurrencySeries::redenomination) and changed by
+/// [`change_redenomination`](fn@CurrencySeries::change_redenomination). Frequency formulas given by
+/// [`CurrencySeriesBuilder::advanced_processes`](fn@CurrencySeriesBuilder::advanced_processes) are read by
+/// [`advanced_processes`](fn@CurrencySeries::advanced_processes) and applied by
+/// [`get_data_with_formulas`](fn@CurrencySeries::get_data_with_formulas) only.
 ///
 /// *Use of this struct and its implemented functions seems complicated, however it is safe and makes some required 
 /// error prone operations automatically without any problem.*
 pub struct CurrencySeries {
     pub ytl_mode: bool,
+    redenomination: Redenomination,
     pub exchange_type: ExchangeType,
     pub currency_code: CurrencyCode,
     pub date_preference: DatePreference,
+    /// frequency formulas applied by [`get_data_with_formulas`](fn@CurrencySeries::get_data_with_formulas).
+    advanced_processes: Option<AdvancedProcesses>,
 }
 
 impl CurrencySeries {
     /// generates single series or dual series with selling and buying with given data.
-    fn generate_series_as_url_format(&self) -> Result<String, ReturnError> {
+    fn generate_series_as_url_format(&self, ytl_mode: bool) -> Result<String, ReturnError> {
         let exchange_types = self.exchange_type.make_required_list();
         
         let series_format: String;  
@@ -383,27 +565,24 @@ impl CurrencySeries {
             return Err(ReturnError::EmptyExchangeType);
         }
 
-        if exchange_types.len() == 2 {
+        if exchange_types.len() > 1 {
 
             series_format =
-            <Self as MakingUrlFormat>::generate_two_combined_currencies_format(
-                &<Self as MakingUrlFormat>::generate_currency_format_for_combination(
-                    &self.currency_code.to_string(),
-                    exchange_types[0],
-                    self.ytl_mode
-                ),
-                &<Self as MakingUrlFormat>::generate_currency_format_for_combination(
-                    &self.currency_code.to_string(), 
-                    exchange_types[1],
-                    self.ytl_mode
-                )
+            <Self as MakingUrlFormat>::generate_combined_currencies_format(
+                exchange_types.iter()
+                    .map(|exchange_type| <Self as MakingUrlFormat>::generate_currency_format_for_combination(
+                        &self.currency_code.to_string(),
+                        exchange_type,
+                        ytl_mode
+                    ))
+                    .collect()
             );
         }
         else {
             series_format = 
             <Self as MakingUrlFormat>::generate_currency_format(
                 &self.currency_code.to_string(), 
-                exchange_types[0], self.ytl_mode
+                exchange_types[0], ytl_mode
             );
         }
 
@@ -443,16 +622,61 @@ impl CurrencySeries {
     ) -> CurrencySeries {
         CurrencySeries {
             ytl_mode,
+            redenomination: Redenomination::Manual,
             exchange_type,
             currency_code,
             date_preference,
+            advanced_processes: None,
         }
     }
 
+    /// creates a [`CurrencySeriesBuilder`](struct@CurrencySeriesBuilder) to build currency series option by option.
+    pub fn builder() -> CurrencySeriesBuilder {
+        CurrencySeriesBuilder::new()
+    }
+
+    /// changes the redenomination preference, which is manual by default.
+    ///
+    /// # Example
+    /// ```
+    ///     use tcmb_evds::date::{DateRange, DatePreference};
+    ///     use tcmb_evds::evds_currency::{ExchangeType, CurrencyCode, CurrencySeries, Redenomination};
+    ///
+    ///
+    ///     let date_range =
+    ///         if let Ok(date_range) = DateRange::from("01-01-1995", "31-12-2015") { date_range }
+    ///         else { return };
+    ///
+    ///     let mut currency_series = CurrencySeries::from(
+    ///         ExchangeType::new(),
+    ///         CurrencyCode::Usd,
+    ///         DatePreference::Multiple(date_range),
+    ///         false
+    ///     );
+    ///
+    ///     // values before 2005 are given in Turkish lira in the same series as the values since 2005.
+    ///     currency_series.change_redenomination(Redenomination::Automatic { rescale: true });
+    /// ```
+    pub fn change_redenomination(&mut self, redenomination: Redenomination) {
+        self.redenomination = redenomination;
+    }
+
+    /// gives the redenomination preference.
+    pub fn redenomination(&self) -> Redenomination {
+        self.redenomination
+    }
+
+    /// gives the frequency formulas given by
+    /// [`CurrencySeriesBuilder::advanced_processes`](fn@CurrencySeriesBuilder::advanced_processes).
+    pub fn advanced_processes(&self) -> Option<&AdvancedProcesses> {
+        self.advanced_processes.as_ref()
+    }
+
 
     /// returns data about just one currency.
     ///
-    /// Single date or multiple dates can be used for this function.
+    /// Single date or multiple dates can be used for this function. Frequency formulas of the currency series are not
+    /// applied, follow [`get_data_with_formulas`](fn@CurrencySeries::get_data_with_formulas) for them.
     ///
     /// This function is used as a method of [`CurrencySeries`](struct@CurrencySeries) because of decreasing amount of
     /// function parameters user entering.
@@ -504,20 +728,168 @@ impl CurrencySeries {
     ///     };
     /// ```
     pub fn get_data(&self, evds: &common::Evds) -> Result<String, ReturnError> {
-        
-        let url_root = "https://evds2.tcmb.gov.tr/service/evds/";
+        self.request_series(evds, |series_format, date_format| {
+            currency::make_request(&Self::compose_url(series_format, date_format, None, evds), evds)
+        })
+    }
 
-        let series_format = self.generate_series_as_url_format()?;
+    /// composes the requests of [`get_data`](fn@CurrencySeries::get_data) without sending them, with the api key
+    /// redacted.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the exchange type is empty or a date is invalid.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::error::Error;
+    /// # use tcmb_evds::common::{ApiKey, ReturnFormat, Evds};
+    ///     use tcmb_evds::evds_currency::{CurrencyCode, CurrencySeries};
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// #   let api_key =
+    /// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+    /// #       else { return Ok(()) };
+    /// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+    ///     let currency_series =
+    ///         CurrencySeries::builder().currency(CurrencyCode::Usd).selling().date("13-12-2011").build()?;
+    ///
+    ///     println!("{}", currency_series.build_request(&evds)?);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn build_request(&self, evds: &common::Evds) -> Result<common::Request, ReturnError> {
+        Ok(evds.compose_request(self.urls(None, evds)?))
+    }
+
+    /// composes the requests of [`get_advanced_data`](fn@CurrencySeries::get_advanced_data) without sending them,
+    /// with the api key redacted.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`get_advanced_data`](fn@CurrencySeries::get_advanced_data) that are
+    /// given before the request.
+    pub fn build_advanced_request(
+        &self,
+        evds: &common::Evds,
+        advanced_processes: &AdvancedProcesses,
+    ) -> Result<common::Request, ReturnError> {
+        self.validate_advanced_processes(advanced_processes)?;
+
+        Ok(evds.compose_request(self.urls(Some(advanced_processes), evds)?))
+    }
 
+    /// generates the url of the series and the dates in url format with the frequency formulas if there are.
+    fn compose_url(
+        series_format: &str,
+        date_format: &str,
+        advanced_processes: Option<&AdvancedProcesses>,
+        evds: &common::Evds,
+    ) -> String {
         let url = format!(
-            "{}{}&{}&{}&{}", 
-            url_root, 
-            series_format, 
-            self.date_preference.generate_url_format(), 
-            evds.get_return_format_as_url(), 
-            evds.get_api_key_as_url());
+            "https://evds2.tcmb.gov.tr/service/evds/{}&{}&{}&{}",
+            series_format,
+            date_format,
+            evds.get_return_format_as_url(),
+            evds.get_api_key_as_url()
+        );
+
+        match advanced_processes {
+            Some(advanced_processes) => format!(
+                "{}&{}&{}&{}",
+                url,
+                advanced_processes.get_aggregation_type_as_url_format(),
+                advanced_processes.get_formula_as_url_format(),
+                advanced_processes.get_data_frequency_as_url_format()
+            ),
+            None => url,
+        }
+    }
+
+    /// generates the urls of the requests in the order they are sent.
+    fn urls(
+        &self,
+        advanced_processes: Option<&AdvancedProcesses>,
+        evds: &common::Evds,
+    ) -> Result<Vec<String>, ReturnError> {
+        let series_and_dates = redenomination::series_and_dates(
+            self.redenomination,
+            self.ytl_mode,
+            &self.date_preference,
+            |ytl_mode| self.generate_series_as_url_format(ytl_mode)
+        )?;
+
+        Ok(series_and_dates.iter()
+            .map(|(series_format, date_format)| Self::compose_url(series_format, date_format, advanced_processes, evds))
+            .collect())
+    }
+
+    /// makes the given request with the series and dates of each period required by the redenomination preference.
+    fn request_series<F>(&self, evds: &common::Evds, request: F) -> Result<String, ReturnError>
+    where
+        F: Fn(&str, &str) -> Result<String, ReturnError>,
+    {
+        redenomination::request_series(
+            self.redenomination,
+            self.ytl_mode,
+            &self.date_preference,
+            evds.get_return_format(),
+            |ytl_mode| self.generate_series_as_url_format(ytl_mode),
+            request
+        )
+    }
+
 
-        currency::make_request(&url)
+    /// returns data about just one currency as [`CurrencyRates`](struct@CurrencyRates) carrying the quotation unit of
+    /// the currency.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`get_data`](fn@CurrencySeries::get_data) and an error if the response
+    /// cannot be parsed.
+    ///
+    /// # Example
+    ///
+    /// Follow [`get_data`](fn@CurrencySeries::get_data) for detailed implementation of *currency_series* and *evds*.
+    ///
+    /// ```
+    /// #   use tcmb_evds::date::{Date, DatePreference};
+    /// #   use tcmb_evds::evds_currency::{ExchangeType, CurrencyCode};
+    /// #   use tcmb_evds::common::{ApiKey, ReturnFormat, Evds};
+    /// #   use tcmb_evds::evds_currency::CurrencySeries;
+    /// #
+    /// #   let date_result = Date::from("13-12-2011");
+    /// #   let date =
+    /// #       if let Ok(date) = date_result { date }
+    /// #       else { return };
+    /// #   let date_preference = DatePreference::Single(date);
+    /// #
+    /// #   let currency_series = CurrencySeries::from(ExchangeType::new(), CurrencyCode::Jpy, date_preference, false);
+    /// #
+    /// #   let api_key =
+    /// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+    /// #       else { return };
+    /// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+    /// #
+    ///     let currency_rates = match currency_series.get_rates(&evds) {
+    ///         Ok(currency_rates) => currency_rates,
+    ///         Err(error) => {
+    ///             println!("{}", error.to_string());
+    ///             return
+    ///         }
+    ///     };
+    ///
+    ///     // prices of a single yen instead of 100 yen.
+    ///     let per_unit = currency_rates.per_unit();
+    /// ```
+    pub fn get_rates(&self, evds: &common::Evds) -> Result<CurrencyRates, ReturnError> {
+        let series_format = self.generate_series_as_url_format(self.ytl_mode)?;
+
+        CurrencyRates::parse_series(
+            &self.get_data(evds)?,
+            evds.get_return_format(),
+            series_format.trim_start_matches("series=")
+        )
     }
 
 
@@ -530,7 +902,14 @@ impl CurrencySeries {
     ///
     /// # Error
     ///  
-    /// This function returns error if internet connection is lost.
+    /// This function returns error if internet connection is lost. It also returns an error before the request if the
+    /// frequency formulas do not fit exchange rates as checked by
+    /// [`validate_for_exchange_rates`](fn@frequency_formulas::AdvancedProcesses::validate_for_exchange_rates), or
+    /// the redenomination is automatic while the formula is not level or the data is more frequent than monthly,
+    /// since EVDS computes the formulas of each side of 01-01-2005 separately.
+    ///
+    /// Follow [`get_unchecked_advanced_data`](fn@CurrencySeries::get_unchecked_advanced_data) to request frequency
+    /// formulas without these checks.
     ///
     /// # Example
     ///
@@ -587,27 +966,85 @@ impl CurrencySeries {
         evds: &common::Evds, 
         advanced_processes: &AdvancedProcesses
     ) -> Result<String, ReturnError> {
-        
-        let url_root = "https://evds2.tcmb.gov.tr/service/evds/";
 
-        if self.exchange_type.are_both_types() {
-            return Err(ReturnError::SingleExchangeTypeExpected)
-        }
+        self.validate_advanced_processes(advanced_processes)?;
 
-        let series_format = self.generate_series_as_url_format()?;
+        self.get_unchecked_advanced_data(evds, advanced_processes)
+    }
 
-        let url = format!(
-            "{}{}&{}&{}&{}&{}&{}&{}", 
-            url_root, series_format, 
-            self.date_preference.generate_url_format(), 
-            evds.get_return_format_as_url(), 
-            evds.get_api_key_as_url(), 
-            advanced_processes.get_aggregation_type_as_url_format(), 
-            advanced_processes.get_formula_as_url_format(), 
-            advanced_processes.get_data_frequency_as_url_format()
-        );
-    
-        currency::make_request(&url)
+    /// checks the frequency formulas as [`get_advanced_data`](fn@CurrencySeries::get_advanced_data) does before the
+    /// request.
+    fn validate_advanced_processes(&self, advanced_processes: &AdvancedProcesses) -> Result<(), ReturnError> {
+        self.exchange_type.validate_single_type()?;
+
+        advanced_processes.validate_for_exchange_rates()?;
+
+        self.redenomination.validate(advanced_processes)
+    }
+
+    /// returns data about just one currency with frequency formulas without checking them against exchange rates.
+    ///
+    /// This function makes the same request as [`get_advanced_data`](fn@CurrencySeries::get_advanced_data) for
+    /// frequency formulas that it rejects, such as cumulative aggregation, whose results may be empty or meaningless.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`get_advanced_data`](fn@CurrencySeries::get_advanced_data) except the
+    /// errors of
+    /// [`validate_for_exchange_rates`](fn@frequency_formulas::AdvancedProcesses::validate_for_exchange_rates).
+    pub fn get_unchecked_advanced_data(
+        &self,
+        evds: &common::Evds,
+        advanced_processes: &AdvancedProcesses
+    ) -> Result<String, ReturnError> {
+
+        self.exchange_type.validate_single_type()?;
+
+        self.redenomination.validate(advanced_processes)?;
+
+        self.request_series(evds, |series_format, date_format| {
+            currency::make_request(&Self::compose_url(series_format, date_format, Some(advanced_processes), evds), evds)
+        })
+    }
+
+    /// returns data about just one currency with the frequency formulas of the currency series, as
+    /// [`get_advanced_data`](fn@CurrencySeries::get_advanced_data) gives.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`get_advanced_data`](fn@CurrencySeries::get_advanced_data) and an error if
+    /// the currency series does not have frequency formulas.
+    ///
+    /// # Example
+    /// ```
+    /// # use std::error::Error;
+    /// # use tcmb_evds::common::{ApiKey, ReturnFormat, Evds};
+    ///     use tcmb_evds::evds_currency::{CurrencyCode, CurrencySeries, frequency_formulas::*};
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    /// #   let api_key =
+    /// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+    /// #       else { return Ok(()) };
+    /// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+    ///     let advanced_processes =
+    ///         AdvancedProcesses::from(AggregationType::End, Formula::Level, DataFrequency::Monthly);
+    ///
+    ///     let currency_series = CurrencySeries::builder()
+    ///         .currency(CurrencyCode::Usd)
+    ///         .selling()
+    ///         .range("13-12-2011", "12-12-2012")
+    ///         .advanced_processes(advanced_processes)
+    ///         .build()?;
+    ///
+    ///     let monthly_data = currency_series.get_data_with_formulas(&evds)?;
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn get_data_with_formulas(&self, evds: &common::Evds) -> Result<String, ReturnError> {
+        match &self.advanced_processes {
+            Some(advanced_processes) => self.get_advanced_data(evds, advanced_processes),
+            None => Err(ReturnError::MissingOption("advanced processes".to_string())),
+        }
     }
 }
 
@@ -620,17 +1057,24 @@ impl traits::MakingUrlFormat for CurrencySeries {}
 ///
 /// This struct accepts both Single and Multiple date options.
 ///
+/// Ytl mode is ignored when [`redenomination`](enum@Redenomination) is automatic, which chooses the series for each
+/// side of 01-01-2005.
+///
+/// The redenomination preference is read by [`redenomination`](fn@MultipleCurrencySeries::redenomination) and
+/// changed by [`change_redenomination`](fn@MultipleCurrencySeries::change_redenomination).
+///
 /// *Use of this struct and its implemented functions seems complicated, however it is safe and makes some required 
 /// error prone operations automatically without any problem.*
 pub struct MultipleCurrencySeries {
     pub ytl_mode: bool,
+    redenomination: Redenomination,
     pub exchange_type: ExchangeType,
     pub currency_codes: CurrencyCodes,
     pub date_preference: DatePreference,
 }
 
 impl MultipleCurrencySeries {
-    fn generate_multiple_series_as_url_format(&self) -> Result<String, ReturnError> {
+    fn generate_multiple_series_as_url_format(&self, ytl_mode: bool) -> Result<String, ReturnError> {
         let currency_codes = self.currency_codes.make_required_list();
         let exchange_types = self.exchange_type.make_required_list();
 
@@ -644,25 +1088,22 @@ impl MultipleCurrencySeries {
             return Err(ReturnError::EmptyExchangeType);
         }
         
-        if exchange_types.len() == 2 {
-            series_format = <Self as MakingUrlFormat>::generate_two_combined_currencies_format(
-                &<Self as MakingUrlFormat>::generate_multiple_currency_format_for_combination(
-                    self.currency_codes.make_required_list(), 
-                    exchange_types[0], 
-                    self.ytl_mode
-                ), 
-                &<Self as MakingUrlFormat>::generate_multiple_currency_format_for_combination(
-                    self.currency_codes.make_required_list(), 
-                    exchange_types[1], 
-                    self.ytl_mode
-                )
+        if exchange_types.len() > 1 {
+            series_format = <Self as MakingUrlFormat>::generate_combined_currencies_format(
+                exchange_types.iter()
+                    .map(|exchange_type| <Self as MakingUrlFormat>::generate_multiple_currency_format_for_combination(
+                        self.currency_codes.make_required_list(), 
+                        exchange_type, 
+                        ytl_mode
+                    ))
+                    .collect()
             );
         }
         else {
             series_format = <Self as MakingUrlFormat>::generate_multiple_currency_format(
                 self.currency_codes.make_required_list(), 
                 exchange_types[0], 
-                self.ytl_mode
+                ytl_mode
             );
         }
 
@@ -674,16 +1115,13 @@ impl MultipleCurrencySeries {
     /// # Example
     /// ```
     ///     use tcmb_evds::date::{DateRange, DatePreference};        
-    ///     use tcmb_evds::evds_currency::{ExchangeType, CurrencyCodes, MultipleCurrencySeries};
+    ///     use tcmb_evds::evds_currency::{ExchangeType, CurrencyCode, CurrencyCodes, MultipleCurrencySeries};
     /// 
     /// 
     ///     let exchange_type = ExchangeType::new();
     ///
-    ///     let mut currency_codes = CurrencyCodes { 
-    ///         usd: true,
-    ///         aud: true,
-    ///         ..Default::default()
-    ///     };
+    ///     let mut currency_codes = CurrencyCodes::new();
+    ///     currency_codes.insert(CurrencyCode::Aud);
     ///
     ///     let date_range_result = DateRange::from("13-12-2011", "12-12-2012");
     ///
@@ -713,16 +1151,39 @@ impl MultipleCurrencySeries {
     ) -> MultipleCurrencySeries {
         MultipleCurrencySeries {
             ytl_mode,
+            redenomination: Redenomination::Manual,
             exchange_type,
             currency_codes,
             date_preference,
         }   
     }
 
+    /// creates a [`MultipleCurrencySeriesBuilder`](struct@MultipleCurrencySeriesBuilder) to build multiple currency
+    /// series option by option.
+    pub fn builder() -> MultipleCurrencySeriesBuilder {
+        MultipleCurrencySeriesBuilder::new()
+    }
+
+    /// changes the redenomination preference, which is manual by default.
+    ///
+    /// Follow [`change_redenomination`](fn@CurrencySeries::change_redenomination) of
+    /// [`CurrencySeries`](struct@CurrencySeries) for an example.
+    pub fn change_redenomination(&mut self, redenomination: Redenomination) {
+        self.redenomination = redenomination;
+    }
+
+    /// gives the redenomination preference.
+    pub fn redenomination(&self) -> Redenomination {
+        self.redenomination
+    }
+
     /// returns data about more than one currency.
     ///
     /// Single date or multiple dates can be used for this function.
     ///
+    /// Currency series exceeding the url limits of [`ClientConfig`](struct@crate::common::ClientConfig) are requested
+    /// in several parts and their observations are merged by date into a single response.
+    ///
     /// This function is used as a method of [`CurrencySeries`](struct@CurrencySeries) because of decreasing amount of
     /// function parameters user entering.
     ///
@@ -736,13 +1197,13 @@ impl MultipleCurrencySeries {
     ///
     /// ```
     /// #   use tcmb_evds::date::{Date, DateRange, DatePreference};        
-    /// #   use tcmb_evds::evds_currency::{ExchangeType, CurrencyCodes, MultipleCurrencySeries};
+    /// #   use tcmb_evds::evds_currency::{ExchangeType, CurrencyCode, CurrencyCodes, MultipleCurrencySeries};
     /// #   use tcmb_evds::common::{ApiKey, ReturnFormat, Evds};    
     /// #
     /// #   let exchange_type = ExchangeType::new();
     /// #
     /// #   let mut currency_codes = CurrencyCodes::new();
-    /// #   currency_codes.aud = true;
+    /// #   currency_codes.insert(CurrencyCode::Aud);
     /// #
     /// #   let date_range_result = DateRange::from("13-12-2011", "12-12-2012");
     /// #   let date_range = 
@@ -779,20 +1240,74 @@ impl MultipleCurrencySeries {
     ///     };
     /// ```
     pub fn get_multiple_data(&self, evds: &common::Evds) -> Result<String, ReturnError> {
-        
-        let url_root = "https://evds2.tcmb.gov.tr/service/evds/";
+        redenomination::request_series(
+            self.redenomination,
+            self.ytl_mode,
+            &self.date_preference,
+            evds.get_return_format(),
+            |ytl_mode| self.generate_multiple_series_as_url_format(ytl_mode),
+            |series_format, date_format| {
+                // Series exceeding the url limits of the client configuration are requested in parts and merged by
+                // date.
+                series_split::request_urls(&Self::urls_in_parts(series_format, date_format, evds), evds, |url| {
+                    currency::make_request(url, evds)
+                })
+            }
+        )
+    }
 
-        let series_format = self.generate_multiple_series_as_url_format()?;
+    /// composes the requests of [`get_multiple_data`](fn@MultipleCurrencySeries::get_multiple_data) without sending
+    /// them, with the api key redacted.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the currency codes or the exchange type is empty or a date is invalid.
+    pub fn build_request(&self, evds: &common::Evds) -> Result<common::Request, ReturnError> {
+        let series_and_dates = redenomination::series_and_dates(
+            self.redenomination,
+            self.ytl_mode,
+            &self.date_preference,
+            |ytl_mode| self.generate_multiple_series_as_url_format(ytl_mode)
+        )?;
+
+        let urls = series_and_dates.iter()
+            .flat_map(|(series_format, date_format)| Self::urls_in_parts(series_format, date_format, evds))
+            .collect();
+
+        Ok(evds.compose_request(urls))
+    }
 
-        let url = format!(
-            "{}{}&{}&{}&{}", 
-            url_root, series_format,
-            self.date_preference.generate_url_format(),
-            evds.get_return_format_as_url(), 
-            evds.get_api_key_as_url()
-        );
+    /// generates the urls of the series and the dates in url format in as many parts as the url limits of the client
+    /// configuration require.
+    fn urls_in_parts(series_format: &str, date_format: &str, evds: &common::Evds) -> Vec<String> {
+        let compose_url = |series: &str| {
+            format!(
+                "https://evds2.tcmb.gov.tr/service/evds/series={}&{}&{}&{}",
+                series,
+                date_format,
+                evds.get_return_format_as_url(),
+                evds.get_api_key_as_url()
+            )
+        };
 
-        currency::make_request(&url)
+        series_split::urls_in_parts(series_format.trim_start_matches("series="), compose_url, evds)
+    }
+
+    /// returns data about more than one currency as [`CurrencyRates`](struct@CurrencyRates) carrying the quotation
+    /// unit of each currency.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`get_multiple_data`](fn@MultipleCurrencySeries::get_multiple_data) and an
+    /// error if the response cannot be parsed.
+    pub fn get_multiple_rates(&self, evds: &common::Evds) -> Result<CurrencyRates, ReturnError> {
+        let series_format = self.generate_multiple_series_as_url_format(self.ytl_mode)?;
+
+        CurrencyRates::parse_series(
+            &self.get_multiple_data(evds)?,
+            evds.get_return_format(),
+            series_format.trim_start_matches("series=")
+        )
     }
 }
 
@@ -806,22 +1321,251 @@ mod test {
     #[test]
     fn should_make_default() {
 
-        let mut currency_codes = CurrencyCodes {
-            aud: true,
-            qar: true,
-            usd: true,
-            kwd: true,
-            ..Default::default()
-        };
+        let mut currency_codes: CurrencyCodes =
+            vec![CurrencyCode::Qar, CurrencyCode::Aud, CurrencyCode::Usd, CurrencyCode::Kwd].into_iter().collect();
 
-        currency_codes.sar = true;
+        assert!(currency_codes.insert(CurrencyCode::Sar));
+        assert!(!currency_codes.insert(CurrencyCode::Usd));
 
         assert!(!currency_codes.is_all_excluded());
+        assert_eq!(vec!["USD", "AUD", "KWD", "SAR", "QAR"], currency_codes.make_required_list());
+
+        assert!(currency_codes.remove(CurrencyCode::Aud));
+        assert!(!currency_codes.contains(CurrencyCode::Aud));
+
+        currency_codes.exclude_all();
+        assert!(currency_codes.is_all_excluded());
+
+        currency_codes.include_all();
+        assert_eq!(CurrencyCode::ALL.to_vec(), currency_codes.into_iter().collect::<Vec<CurrencyCode>>());
+    }
+
+    #[test]
+    fn should_combine_currency_code_sets() {
+        let first: CurrencyCodes = " usd, eur,GBP ".parse().unwrap();
+        let second: CurrencyCodes = "gbp,jpy".parse().unwrap();
+
+        assert_eq!("USD,EUR,GBP,JPY", first.union(&second).to_string());
+        assert_eq!("GBP", first.intersection(&second).to_string());
+        assert_eq!("USD,EUR", first.difference(&second).to_string());
+        assert_eq!(3, first.len());
+
+        assert_eq!(Ok(CurrencyCodes::default()), "".parse::<CurrencyCodes>());
+        assert_eq!(Err(ReturnError::InvalidCurrencyCode), "USD,EURO".parse::<CurrencyCodes>());
+    }
+
+    #[test]
+    fn should_parse_only_known_currency_codes() {
+        use std::convert::TryFrom;
 
-        let currency_codes_list = currency_codes.make_required_list();
+        assert_eq!(Ok(CurrencyCode::Usd), " usd".parse::<CurrencyCode>());
+        assert_eq!(Ok(CurrencyCode::Xdr), CurrencyCode::try_from("XDR"));
+        assert_eq!(Err(ReturnError::InvalidCurrencyCode), "xyz".parse::<CurrencyCode>());
+        assert_eq!(Err(ReturnError::InvalidCurrencyCode), ConvertingToRustEnum::<CurrencyCode>::convert(&"qa"));
 
-        for code in currency_codes_list {
-            println!("{}", &code);
+        for currency_code in CurrencyCode::ALL.iter() {
+            assert_eq!(currency_code.to_string(), currency_code.metadata().code);
         }
+
+        assert_eq!(Some(3), CurrencyCode::Kwd.metadata().minor_units);
+        assert_eq!("Japanese Yen", CurrencyCode::Jpy.metadata().name_eng);
+    }
+
+    #[test]
+    fn should_follow_units_of_central_bank() {
+        // Currencies and units of the indicative exchange rates of the central bank, as in
+        // https://www.tcmb.gov.tr/kurlar/today.xml, where the rest of each element is left out.
+        let today = r#"
+            <Currency CrossOrder="0" Kod="USD" CurrencyCode="USD"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="1" Kod="AUD" CurrencyCode="AUD"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="2" Kod="DKK" CurrencyCode="DKK"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="9" Kod="EUR" CurrencyCode="EUR"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="3" Kod="GBP" CurrencyCode="GBP"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="4" Kod="CHF" CurrencyCode="CHF"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="5" Kod="SEK" CurrencyCode="SEK"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="6" Kod="CAD" CurrencyCode="CAD"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="7" Kod="KWD" CurrencyCode="KWD"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="8" Kod="NOK" CurrencyCode="NOK"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="10" Kod="SAR" CurrencyCode="SAR"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="11" Kod="JPY" CurrencyCode="JPY"><Unit>100</Unit></Currency>
+            <Currency CrossOrder="12" Kod="BGN" CurrencyCode="BGN"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="13" Kod="RON" CurrencyCode="RON"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="14" Kod="RUB" CurrencyCode="RUB"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="15" Kod="IRR" CurrencyCode="IRR"><Unit>100</Unit></Currency>
+            <Currency CrossOrder="16" Kod="CNY" CurrencyCode="CNY"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="17" Kod="PKR" CurrencyCode="PKR"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="18" Kod="QAR" CurrencyCode="QAR"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="19" Kod="KRW" CurrencyCode="KRW"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="20" Kod="AZN" CurrencyCode="AZN"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="21" Kod="AED" CurrencyCode="AED"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="22" Kod="KZT" CurrencyCode="KZT"><Unit>1</Unit></Currency>
+            <Currency CrossOrder="23" Kod="XDR" CurrencyCode="XDR"><Unit>1</Unit></Currency>
+        "#;
+
+        let units: Vec<(String, u32)> = today.split("Kod=\"").skip(1)
+            .map(|element| {
+                let code = element[..3].to_string();
+                let unit = element.split("<Unit>").nth(1).and_then(|rest| rest.split('<').next()).unwrap();
+
+                (code, unit.parse().unwrap())
+            })
+            .collect();
+
+        assert_eq!(CurrencyCode::ALL.len(), units.len());
+
+        for (code, unit) in units {
+            let currency_code: CurrencyCode = code.parse().unwrap();
+
+            assert_eq!(unit, currency_code.metadata().unit, "unit of {}", code);
+        }
+    }
+
+    #[test]
+    fn should_build_requests_without_sending() {
+        use crate::common::{ApiKey, Evds, ReturnFormat};
+
+        let evds = Evds::from(ApiKey::unchecked("secret"), ReturnFormat::Csv);
+
+        let mut currency_series = CurrencySeries::builder()
+            .currency(CurrencyCode::Usd)
+            .buying()
+            .range("30-12-2004", "03-01-2005")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            "https://evds2.tcmb.gov.tr/service/evds/series=TP.DK.USD.A&startDate=30-12-2004&endDate=03-01-2005\
+            &type=csv&key=REDACTED",
+            currency_series.build_request(&evds).unwrap().to_url()
+        );
+
+        currency_series.change_redenomination(Redenomination::Automatic { rescale: true });
+
+        let request = currency_series.build_request(&evds).unwrap();
+
+        assert_eq!(2, request.urls.len());
+        assert!(request.urls[1].contains("series=TP.DK.USD.A.YTL&startDate=01-01-2005&endDate=03-01-2005"));
+        assert!(!request.to_string().contains("secret"));
+
+        let currency_series = CurrencySeries::builder()
+            .currency(CurrencyCode::Usd)
+            .buying()
+            .range("01-01-2012", "31-12-2012")
+            .advanced_processes(AdvancedProcesses::from(AggregationType::End, Formula::Level, DataFrequency::Monthly))
+            .build()
+            .unwrap();
+
+        let advanced_processes = currency_series.advanced_processes().unwrap();
+
+        assert!(!currency_series.build_request(&evds).unwrap().to_url().contains("formulas="));
+        assert_eq!(
+            "https://evds2.tcmb.gov.tr/service/evds/series=TP.DK.USD.A&startDate=01-01-2012&endDate=31-12-2012\
+            &type=csv&key=REDACTED&aggregationTypes=last&formulas=0&frequency=5",
+            currency_series.build_advanced_request(&evds, advanced_processes).unwrap().to_url()
+        );
+
+        let cumulative = AdvancedProcesses::from(AggregationType::Cumulative, Formula::Level, DataFrequency::Monthly);
+
+        assert!(matches!(
+            currency_series.build_advanced_request(&evds, &cumulative),
+            Err(ReturnError::IncompatibleAdvancedProcesses(Incompatibility::SumOfRates))
+        ));
+        assert!(currency_series.urls(Some(&cumulative), &evds).unwrap()[0]
+            .contains("&aggregationTypes=sum&formulas=0&frequency=5"));
+
+        let mut multiple_currency_series = MultipleCurrencySeries::from(
+            ExchangeType::new(),
+            CurrencyCodes::default(),
+            DatePreference::Multiple(crate::date::DateRange::from("30-12-2004", "03-01-2005").unwrap()),
+            false
+        );
+
+        assert_eq!(Err(ReturnError::EmptyCurrencyCodes), multiple_currency_series.build_request(&evds));
+
+        let mut client_config = common::ClientConfig::new();
+        client_config.change_max_series_per_request(Some(1));
+
+        let mut evds = evds;
+        evds.change_client_config(client_config);
+
+        multiple_currency_series.currency_codes = vec![CurrencyCode::Usd, CurrencyCode::Eur].into_iter().collect();
+        multiple_currency_series.change_redenomination(Redenomination::Automatic { rescale: false });
+
+        let request = multiple_currency_series.build_request(&evds).unwrap();
+        let series_and_dates: Vec<&str> = request.urls.iter()
+            .map(|url| url.trim_start_matches("https://evds2.tcmb.gov.tr/service/evds/").split("&type").next().unwrap())
+            .collect();
+
+        assert_eq!(
+            vec![
+                "series=TP.DK.USD.S&startDate=30-12-2004&endDate=31-12-2004",
+                "series=TP.DK.EUR.S&startDate=30-12-2004&endDate=31-12-2004",
+                "series=TP.DK.USD.S.YTL&startDate=01-01-2005&endDate=03-01-2005",
+                "series=TP.DK.EUR.S.YTL&startDate=01-01-2005&endDate=03-01-2005",
+            ],
+            series_and_dates
+        );
+    }
+
+    #[test]
+    fn should_reject_empty_exchange_type_with_frequency_formulas() {
+        use crate::common::{ApiKey, Evds, ReturnFormat};
+
+        let evds = Evds::from(ApiKey::unchecked("secret"), ReturnFormat::Json);
+        let advanced_processes = AdvancedProcesses::from(AggregationType::End, Formula::Level, DataFrequency::Monthly);
+
+        let currency_series = CurrencySeries::from(
+            ExchangeType::from(false, false),
+            CurrencyCode::Usd,
+            DatePreference::Single(crate::date::Date::from("13-12-2011").unwrap()),
+            false
+        );
+
+        assert_eq!(Err(ReturnError::EmptyExchangeType), currency_series.get_advanced_data(&evds, &advanced_processes));
+        assert_eq!(
+            Err(ReturnError::EmptyExchangeType),
+            currency_series.get_unchecked_advanced_data(&evds, &advanced_processes)
+        );
+        assert_eq!(
+            Err(ReturnError::EmptyExchangeType),
+            currency_series.build_advanced_request(&evds, &advanced_processes)
+        );
+    }
+
+    #[test]
+    fn should_generate_series_of_each_exchange_type() {
+        let currency_series = CurrencySeries::from(
+            ExchangeType::from_all(true, false, true, false),
+            CurrencyCode::Usd,
+            DatePreference::Single(crate::date::Date::from("13-12-2011").unwrap()),
+            true
+        );
+
+        assert_eq!(
+            Ok("series=TP.DK.USD.A.YTL-TP.DK.USD.A.EF.YTL".to_string()),
+            currency_series.generate_series_as_url_format(currency_series.ytl_mode)
+        );
+
+        let mut exchange_type = ExchangeType::new();
+        exchange_type.select_effective_types();
+
+        let multiple_currency_series = MultipleCurrencySeries::from(
+            exchange_type,
+            "USD,KRW".parse().unwrap(),
+            DatePreference::Single(crate::date::Date::from("13-12-2011").unwrap()),
+            false
+        );
+
+        assert_eq!(
+            Ok("series=TP.DK.USD.A.EF-TP.DK.KRW.A.EF-TP.DK.USD.S.EF-TP.DK.KRW.S.EF".to_string()),
+            multiple_currency_series.generate_multiple_series_as_url_format(false)
+        );
+
+        let mut all_types = ExchangeType::new();
+        all_types.select_all_types();
+
+        assert_eq!(vec!["A", "S", "A.EF", "S.EF"], all_types.make_required_list());
+        assert_eq!(Err(ReturnError::SingleExchangeTypeExpected), all_types.validate_single_type());
+        assert_eq!(Err(ReturnError::EmptyExchangeType), ExchangeType::from(false, false).validate_single_type());
     }
 }
@@ -0,0 +1,133 @@
+use super::CurrencyCode;
+use crate::common::ReturnFormat;
+use crate::error::ReturnError;
+use crate::response::Observations;
+
+
+/// gives the quotation unit of a currency series such as 100 for "TP_DK_JPY_A" or "TP.DK.JPY.A".
+///
+/// Series that are not exchange rate series starting with "TP_DK" or "TP.DK" have the unit 1.
+pub(crate) fn unit_of_series(series: &str) -> u32 {
+    let mut parts = series.trim().split(['_', '.']);
+
+    if parts.next() != Some("TP") || parts.next() != Some("DK") { return 1 }
+
+    parts.next()
+        .and_then(|code| code.parse::<CurrencyCode>().ok())
+        .map_or(1, |currency_code| currency_code.metadata().unit)
+}
+
+
+/// is the typed form of a currency response with the quotation unit of each series.
+///
+/// The central bank quotes some currencies such as "JPY" per 100 units, therefore the values of their series are 100
+/// times the price of a unit. [`per_unit`](fn@CurrencyRates::per_unit) gives the prices of a unit of each currency.
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::common::ReturnFormat;
+///     use tcmb_evds::evds_currency::CurrencyRates;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let response = r#"{"items":[{"Tarih":"13-12-2011","TP_DK_JPY_A":"2.3628","TP_DK_USD_A":"1.8365"}]}"#;
+///
+///     let currency_rates = CurrencyRates::parse(response, &ReturnFormat::Json)?;
+///
+///     assert_eq!(vec![100, 1], currency_rates.units);
+///     assert_eq!(Some(0.023628), currency_rates.per_unit().value("13-12-2011", "TP.DK.JPY.A"));
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct CurrencyRates {
+    /// values as quoted by the central bank.
+    pub observations: Observations,
+    /// quotation units in the order of the series of the observations.
+    pub units: Vec<u32>,
+}
+
+impl CurrencyRates {
+    /// creates currency rates by finding the quotation unit of each series of the observations.
+    pub fn from(observations: Observations) -> CurrencyRates {
+        let units = observations.series.iter().map(|series| unit_of_series(series)).collect();
+
+        CurrencyRates { observations, units }
+    }
+
+    /// parses a currency response received in given return format.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`Observations::parse`](fn@Observations::parse).
+    pub fn parse(response: &str, return_format: &ReturnFormat) -> Result<CurrencyRates, ReturnError> {
+        Ok(CurrencyRates::from(Observations::parse(response, return_format)?))
+    }
+
+    /// parses a currency response of the given data series, where an empty response gives the series without
+    /// observations.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`Observations::parse_series`](fn@Observations::parse_series).
+    pub fn parse_series(
+        response: &str,
+        return_format: &ReturnFormat,
+        data_series: &str,
+    ) -> Result<CurrencyRates, ReturnError> {
+        Ok(CurrencyRates::from(Observations::parse_series(response, return_format, data_series)?))
+    }
+
+    /// gives the quotation unit of the series if there is the series.
+    pub fn unit(&self, series: &str) -> Option<u32> {
+        self.observations.series_index(series).map(|index| self.units[index])
+    }
+
+    /// gives the observations whose values are the prices of a unit of each currency.
+    pub fn per_unit(&self) -> Observations {
+        let mut observations = self.observations.clone();
+
+        for row in &mut observations.rows {
+            for (value, unit) in row.values.iter_mut().zip(&self.units) {
+                *value = value.map(|value| value / *unit as f64);
+            }
+        }
+
+        observations
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Observation;
+
+    #[test]
+    fn should_normalise_per_hundred_quotes() {
+        let currency_rates = CurrencyRates::from(Observations {
+            series: vec!["TP_DK_JPY_S_YTL".to_string(), "TP_DK_EUR_S".to_string(), "TP_FG_J0".to_string()],
+            rows: vec![Observation { date: "13-12-2011".to_string(), values: vec![Some(2.4), Some(2.45), None] }],
+        });
+
+        assert_eq!(vec![100, 1, 1], currency_rates.units);
+        assert_eq!(Some(100), currency_rates.unit("TP.DK.JPY.S.YTL"));
+        assert_eq!(None, currency_rates.unit("TP.DK.USD.S"));
+
+        let per_unit = currency_rates.per_unit();
+
+        assert_eq!(Some(0.024), per_unit.value("13-12-2011", "TP_DK_JPY_S_YTL"));
+        assert_eq!(Some(2.45), per_unit.value("13-12-2011", "TP_DK_EUR_S"));
+        assert_eq!(Some(2.4), currency_rates.observations.value("13-12-2011", "TP_DK_JPY_S_YTL"));
+    }
+
+    #[test]
+    fn should_find_units_of_exchange_rate_series_only() {
+        assert_eq!(100, unit_of_series("TP.DK.JPY.A"));
+        assert_eq!(100, unit_of_series("TP_DK_JPY_A_YTL"));
+        assert_eq!(1, unit_of_series("TP.XX.JPY.A"));
+        assert_eq!(1, unit_of_series("TP_BK_JPY_A"));
+        assert_eq!(1, unit_of_series("JPY"));
+    }
+}
@@ -0,0 +1,324 @@
+use super::frequency_formulas::{AdvancedProcesses, DataFrequency, Formula, Incompatibility};
+use crate::common::ReturnFormat;
+use crate::date::calendar::Day;
+use crate::date::DatePreference;
+use crate::error::ReturnError;
+use crate::response::{self, series_name, Table, DATE_COLUMN};
+use crate::traits::MakingUrlFormat;
+
+
+/// is the first day of Turkish lira, when 1,000,000 old Turkish lira became 1 new Turkish lira.
+const REDENOMINATION_DAY: Day = Day { year: 2005, month: 1, day: 1 };
+
+/// is the number of old Turkish lira in a Turkish lira.
+const OLD_LIRA_PER_LIRA: f64 = 1_000_000.0;
+
+
+/// configures which currency series are requested for the periods before and after the redenomination of Turkish
+/// lira on 01-01-2005.
+///
+/// Currency series such as "TP.DK.USD.A" are quoted in old Turkish lira before 2005, while series with the "YTL"
+/// suffix such as "TP.DK.USD.A.YTL" are quoted in Turkish lira since 2005.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum Redenomination {
+    /// requests the series chosen by the ytl mode for the whole period.
+    #[default]
+    Manual,
+    /// requests series without "YTL" suffix for the days before 01-01-2005 and series with "YTL" suffix from
+    /// 01-01-2005, in a single response.
+    ///
+    /// When `rescale` is true, values before 2005 are divided by 1,000,000 and given in the columns of the series with
+    /// "YTL" suffix, so that each currency has a single series in Turkish lira. Otherwise, values before 2005 are given
+    /// as they are in the columns of the series without "YTL" suffix.
+    Automatic { rescale: bool },
+}
+
+impl Redenomination {
+    /// checks that frequency formulas can be requested with the redenomination preference.
+    ///
+    /// Automatic redenomination requests each side of 01-01-2005 separately, so that EVDS computes the formulas of each
+    /// side without the other. Only the level formula with monthly or less frequent data, whose periods start on
+    /// 01-01-2005, gives the same values as a single request and can be rescaled.
+    ///
+    /// # Error
+    ///
+    /// This function returns [`ReturnError::IncompatibleAdvancedProcesses`](crate::error::ReturnError) if the
+    /// redenomination is automatic and the formula is not level or the data is more frequent than monthly.
+    pub(crate) fn validate(&self, advanced_processes: &AdvancedProcesses) -> Result<(), ReturnError> {
+        let is_split = matches!(self, Redenomination::Automatic { .. });
+        let is_level = advanced_processes.formula == Formula::Level;
+        let is_monthly = advanced_processes.data_frequency.order() >= DataFrequency::Monthly.order();
+
+        if is_split && !(is_level && is_monthly) {
+            return Err(ReturnError::IncompatibleAdvancedProcesses(Incompatibility::AcrossRedenomination {
+                formula: advanced_processes.formula,
+                data_frequency: advanced_processes.data_frequency,
+            }));
+        }
+
+        Ok(())
+    }
+}
+
+
+/// is a part of a requested period quoted in a single lira.
+#[derive(Debug, PartialEq)]
+struct Period {
+    start_date: String,
+    end_date: String,
+    /// is true for the part quoted in Turkish lira and false for the part quoted in old Turkish lira.
+    ytl_mode: bool,
+}
+
+impl Period {
+    /// generates the dates of the period in url format.
+    fn generate_url_format(&self) -> String {
+        format!("startDate={}&endDate={}", self.start_date, self.end_date)
+    }
+}
+
+/// divides the period at the redenomination of Turkish lira.
+///
+/// # Error
+///
+/// This function returns an error if a date is not a "day-month-year" formatted date.
+fn split_period(start_date: &str, end_date: &str) -> Result<Vec<Period>, ReturnError> {
+    let start = Day::parse(start_date).ok_or(ReturnError::InvalidDate)?;
+    let end = Day::parse(end_date).ok_or(ReturnError::InvalidDate)?;
+
+    let period = |start: Day, end: Day, ytl_mode: bool| Period {
+        start_date: start.format(),
+        end_date: end.format(),
+        ytl_mode,
+    };
+
+    if end < REDENOMINATION_DAY {
+        return Ok(vec![period(start, end, false)]);
+    }
+
+    if start >= REDENOMINATION_DAY {
+        return Ok(vec![period(start, end, true)]);
+    }
+
+    Ok(vec![
+        period(start, REDENOMINATION_DAY.add_days(-1), false),
+        period(REDENOMINATION_DAY, end, true),
+    ])
+}
+
+/// requests currency series in the way the redenomination preference requires.
+///
+/// `generate_series` gives the series in url format for a ytl mode and `request` makes a request with given series
+/// and dates in url format.
+///
+/// # Error
+///
+/// This function returns the errors of the given functions and the errors of joining the responses.
+pub(crate) fn request_series<G, F>(
+    redenomination: Redenomination,
+    ytl_mode: bool,
+    date_preference: &DatePreference,
+    return_format: &ReturnFormat,
+    generate_series: G,
+    request: F,
+) -> Result<String, ReturnError>
+where
+    G: Fn(bool) -> Result<String, ReturnError>,
+    F: Fn(&str, &str) -> Result<String, ReturnError>,
+{
+    match redenomination {
+        Redenomination::Manual => request(&generate_series(ytl_mode)?, &date_preference.generate_url_format()),
+        Redenomination::Automatic { rescale } => {
+            let series_format = generate_series(false)?;
+            let series: Vec<&str> = series_format.trim_start_matches("series=").split('-').collect();
+
+            request_in_periods(date_preference.get_dates(), &series, rescale, return_format, |period| {
+                request(&generate_series(period.ytl_mode)?, &period.generate_url_format())
+            })
+        },
+    }
+}
+
+/// gives the series and the dates in url format of each request made by [`request_series`](fn@request_series), in
+/// the order they are requested.
+///
+/// # Error
+///
+/// This function returns the errors of `generate_series` and an error if a date is invalid.
+pub(crate) fn series_and_dates<G>(
+    redenomination: Redenomination,
+    ytl_mode: bool,
+    date_preference: &DatePreference,
+    generate_series: G,
+) -> Result<Vec<(String, String)>, ReturnError>
+where
+    G: Fn(bool) -> Result<String, ReturnError>,
+{
+    match redenomination {
+        Redenomination::Manual => Ok(vec![(generate_series(ytl_mode)?, date_preference.generate_url_format())]),
+        Redenomination::Automatic { .. } => {
+            // Series are generated before the dates are checked, so that errors are given as request_series gives.
+            generate_series(false)?;
+
+            let (start_date, end_date) = date_preference.get_dates();
+
+            split_period(start_date, end_date)?.iter()
+                .map(|period| Ok((generate_series(period.ytl_mode)?, period.generate_url_format())))
+                .collect()
+        },
+    }
+}
+
+/// requests each part of the period with its own series and joins the responses into a single response.
+///
+/// `series` contains the codes of the requested series without "YTL" suffix, which are renamed to their versions
+/// with "YTL" suffix when values before 2005 are rescaled.
+///
+/// # Error
+///
+/// This function returns an error if a date is invalid, a request fails or a response cannot be parsed.
+fn request_in_periods<F>(
+    (start_date, end_date): (&str, &str),
+    series: &[&str],
+    rescale: bool,
+    return_format: &ReturnFormat,
+    request: F,
+) -> Result<String, ReturnError>
+where
+    F: Fn(&Period) -> Result<String, ReturnError>,
+{
+    let periods = split_period(start_date, end_date)?;
+
+    if periods.len() == 1 && (periods[0].ytl_mode || !rescale) {
+        return request(&periods[0]);
+    }
+
+    let tables = periods.iter()
+        .map(|period| {
+            let mut table = Table::parse(&request(period)?, return_format)?;
+
+            if !period.ytl_mode && rescale { rescale_old_lira(&mut table, series)?; }
+
+            Ok(table)
+        })
+        .collect::<Result<Vec<Table>, ReturnError>>()?;
+
+    Ok(Table::merge(tables)?.render(return_format))
+}
+
+/// converts the values of given series from old Turkish lira to Turkish lira and renames their columns to the
+/// series with "YTL" suffix.
+///
+/// # Error
+///
+/// This function returns an error if a value of the series is not a number.
+fn rescale_old_lira(table: &mut Table, series: &[&str]) -> Result<(), ReturnError> {
+    let names: Vec<String> = series.iter().map(|series| series_name(series)).collect();
+
+    for (index, column) in table.columns.iter_mut().enumerate() {
+        if column == DATE_COLUMN || !names.contains(column) { continue }
+
+        column.push_str("_YTL");
+
+        for row in table.rows.iter_mut() {
+            if let Some(cell) = row[index].as_mut() {
+                if cell.trim().is_empty() { continue }
+
+                *cell = (response::parse_value(cell)? / OLD_LIRA_PER_LIRA).to_string();
+            }
+        }
+    }
+
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evds_currency::frequency_formulas::AggregationType;
+
+    #[test]
+    fn should_split_at_redenomination() {
+        assert_eq!(
+            vec![
+                Period { start_date: "01-01-1995".to_string(), end_date: "31-12-2004".to_string(), ytl_mode: false },
+                Period { start_date: "01-01-2005".to_string(), end_date: "31-12-2015".to_string(), ytl_mode: true },
+            ],
+            split_period("01-01-1995", "31-12-2015").unwrap()
+        );
+        assert_eq!(1, split_period("01-01-2005", "01-01-2005").unwrap().len());
+        assert_eq!(Err(ReturnError::InvalidDate), split_period("2004-12", "01-01-2005"));
+    }
+
+    fn across_redenomination(
+        rescale: bool,
+        formula: Formula,
+        data_frequency: DataFrequency,
+    ) -> Result<(), ReturnError> {
+        let advanced_processes = AdvancedProcesses::from(AggregationType::Average, formula, data_frequency);
+
+        Redenomination::Automatic { rescale }.validate(&advanced_processes)
+    }
+
+    #[test]
+    fn should_reject_changes_without_a_base_across_redenomination() {
+        assert_eq!(
+            Err(ReturnError::IncompatibleAdvancedProcesses(Incompatibility::AcrossRedenomination {
+                formula: Formula::PercentageChange,
+                data_frequency: DataFrequency::Monthly,
+            })),
+            across_redenomination(false, Formula::PercentageChange, DataFrequency::Monthly)
+        );
+        assert!(across_redenomination(false, Formula::YearToYearPercentChange, DataFrequency::Annual).is_err());
+    }
+
+    #[test]
+    fn should_reject_rescaling_formulas_other_than_level() {
+        assert!(across_redenomination(true, Formula::Difference, DataFrequency::Monthly).is_err());
+        assert!(across_redenomination(true, Formula::MovingAverage, DataFrequency::Quarterly).is_err());
+        assert_eq!(Ok(()), across_redenomination(true, Formula::Level, DataFrequency::Monthly));
+        assert_eq!(Ok(()), across_redenomination(true, Formula::Level, DataFrequency::Annual));
+    }
+
+    #[test]
+    fn should_reject_periods_spanning_redenomination() {
+        assert!(across_redenomination(false, Formula::Level, DataFrequency::WeeklyFriday).is_err());
+        assert!(across_redenomination(true, Formula::Level, DataFrequency::TwiceMonthly).is_err());
+        assert!(across_redenomination(false, Formula::Level, DataFrequency::Business).is_err());
+
+        let advanced_processes =
+            AdvancedProcesses::from(AggregationType::Average, Formula::PercentageChange, DataFrequency::WeeklyFriday);
+
+        assert_eq!(Ok(()), Redenomination::Manual.validate(&advanced_processes));
+    }
+
+    #[test]
+    fn should_join_periods_into_a_single_series() {
+        let request = |period: &Period| {
+            Ok(if period.ytl_mode {
+                "Tarih,TP_DK_USD_A_YTL\n03-01-2005,1.3421\n".to_string()
+            }
+            else {
+                "Tarih,TP_DK_USD_A\n31-12-2004,1342100\n".to_string()
+            })
+        };
+
+        let response =
+            request_in_periods(("31-12-2004", "03-01-2005"), &["TP.DK.USD.A"], true, &ReturnFormat::Csv, request)
+                .unwrap();
+        let table = Table::parse(&response, &ReturnFormat::Csv).unwrap();
+
+        assert_eq!(vec!["Tarih", "TP_DK_USD_A_YTL"], table.columns);
+        assert_eq!(Some("1.3421".to_string()), table.rows[0][1]);
+
+        let response =
+            request_in_periods(("31-12-2004", "03-01-2005"), &["TP.DK.USD.A"], false, &ReturnFormat::Csv, request)
+                .unwrap();
+
+        assert_eq!(
+            vec!["Tarih", "TP_DK_USD_A", "TP_DK_USD_A_YTL"],
+            Table::parse(&response, &ReturnFormat::Csv).unwrap().columns
+        );
+    }
+}
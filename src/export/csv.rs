@@ -0,0 +1,130 @@
+use std::io::Write;
+
+use super::{columns, long_rows, to_export_error, DecimalSeparator, ExportOptions, Layout};
+use crate::error::ReturnError;
+use crate::response::Observations;
+
+
+/// quotes a field if it contains the delimiter, a quote or a line break.
+fn quote_field(field: &str, delimiter: char) -> String {
+    if field.contains(delimiter) || field.contains('"') || field.contains('\n') {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+
+    field.to_string()
+}
+
+/// writes the fields as a line.
+fn write_line<W: Write>(writer: &mut W, fields: &[String], delimiter: char) -> Result<(), ReturnError> {
+    let fields: Vec<String> = fields.iter().map(|field| quote_field(field, delimiter)).collect();
+
+    writeln!(writer, "{}", fields.join(&delimiter.to_string())).map_err(to_export_error)
+}
+
+/// writes the observations as CSV with a header line in the layout and the formatting of the options.
+///
+/// Fields are separated with commas, or with semicolons when values are written with a decimal comma. Missing values
+/// are written as empty fields.
+///
+/// This function is available with the `export_csv` feature.
+///
+/// # Error
+///
+/// This function returns an error if the writer fails or a date cannot be formatted.
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::common::ReturnFormat;
+///     use tcmb_evds::export::{self, ExportOptions, Layout};
+///     use tcmb_evds::response::Observations;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let response = r#"{"totalCount":1,"items":[{"Tarih":"13-12-2011","TP_DK_USD_A":"1.8365"}]}"#;
+///     let observations = Observations::parse(response, &ReturnFormat::Json)?;
+///
+///     let mut export_options = ExportOptions::new();
+///     export_options.change_layout(Layout::Wide);
+///
+///     let mut output = Vec::new();
+///     export::write_csv(&observations, &export_options, &mut output)?;
+///
+///     assert_eq!("date,TP_DK_USD_A\n13-12-2011,1.8365\n", String::from_utf8(output)?);
+/// #   Ok(())
+/// # }
+/// ```
+pub fn write_csv<W: Write>(
+    observations: &Observations,
+    options: &ExportOptions,
+    mut writer: W,
+) -> Result<(), ReturnError> {
+    let delimiter = match options.decimal_separator {
+        DecimalSeparator::Point => ',',
+        DecimalSeparator::Comma => ';',
+    };
+
+    let format_value = |value: Option<f64>| value.map(|value| options.format_value(value)).unwrap_or_default();
+
+    let header: Vec<String> = columns(observations, options.layout).into_iter().map(String::from).collect();
+    write_line(&mut writer, &header, delimiter)?;
+
+    match options.layout {
+        Layout::Long => for (date, series, value) in long_rows(observations) {
+            write_line(&mut writer, &[options.format_date(date)?, series.to_string(), format_value(value)], delimiter)?;
+        },
+        Layout::Wide => for row in &observations.rows {
+            let fields: Vec<String> = std::iter::once(options.format_date(&row.date)?)
+                .chain(row.values.iter().map(|value| format_value(*value)))
+                .collect();
+
+            write_line(&mut writer, &fields, delimiter)?;
+        },
+    }
+
+    writer.flush().map_err(to_export_error)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::export::DateFormat;
+    use crate::response::Observation;
+
+    #[test]
+    fn should_write_long_and_wide_layouts() {
+        let observations = Observations {
+            series: vec!["TP_DK_USD_A".to_string(), "TP_DK_EUR_A".to_string()],
+            rows: vec![
+                Observation { date: "13-12-2011".to_string(), values: vec![Some(1.8365), None] },
+                Observation { date: "14-12-2011".to_string(), values: vec![Some(1.8441), Some(2.4012)] },
+            ],
+        };
+
+        let mut export_options = ExportOptions::new();
+        let mut output = Vec::new();
+
+        write_csv(&observations, &export_options, &mut output).unwrap();
+
+        assert_eq!(
+            "date,series,value\n13-12-2011,TP_DK_USD_A,1.8365\n13-12-2011,TP_DK_EUR_A,\n\
+            14-12-2011,TP_DK_USD_A,1.8441\n14-12-2011,TP_DK_EUR_A,2.4012\n",
+            String::from_utf8(output).unwrap()
+        );
+
+        export_options.change_layout(Layout::Wide);
+        export_options.change_date_format(DateFormat::Iso);
+        export_options.change_decimal_separator(DecimalSeparator::Comma);
+        export_options.change_precision(Some(2));
+
+        let mut output = Vec::new();
+
+        write_csv(&observations, &export_options, &mut output).unwrap();
+
+        assert_eq!(
+            "date;TP_DK_USD_A;TP_DK_EUR_A\n2011-12-13;1,84;\n2011-12-14;1,84;2,40\n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+}
@@ -0,0 +1,127 @@
+use std::io::Write;
+
+use serde_json::{Map, Number, Value};
+
+use super::{columns, long_rows, to_export_error, DecimalSeparator, ExportOptions, Layout};
+use crate::error::ReturnError;
+use crate::response::Observations;
+
+
+/// converts the value to a JSON number, or to a string when values are written with a decimal comma.
+fn to_json(value: Option<f64>, options: &ExportOptions) -> Value {
+    let value = match value {
+        Some(value) => value,
+        None => return Value::Null,
+    };
+
+    match options.decimal_separator {
+        DecimalSeparator::Point => Number::from_f64(options.round(value)).map(Value::Number).unwrap_or(Value::Null),
+        DecimalSeparator::Comma => Value::String(options.format_value(value)),
+    }
+}
+
+/// writes the observations as JSON Lines, an object on each line, in the layout and the formatting of the options.
+///
+/// Values are JSON numbers rounded to the precision if there is. Since JSON numbers always have a decimal point, values
+/// are written as strings when a decimal comma is chosen. Missing values are `null`.
+///
+/// This function is available with the `export_jsonl` feature.
+///
+/// # Error
+///
+/// This function returns an error if the writer fails or a date cannot be formatted.
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::common::ReturnFormat;
+///     use tcmb_evds::export::{self, ExportOptions};
+///     use tcmb_evds::response::Observations;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let response = r#"{"totalCount":1,"items":[{"Tarih":"13-12-2011","TP_DK_USD_A":"1.8365"}]}"#;
+///     let observations = Observations::parse(response, &ReturnFormat::Json)?;
+///
+///     let mut output = Vec::new();
+///     export::write_jsonl(&observations, &ExportOptions::new(), &mut output)?;
+///
+///     assert_eq!(
+///         "{\"date\":\"13-12-2011\",\"series\":\"TP_DK_USD_A\",\"value\":1.8365}\n",
+///         String::from_utf8(output)?
+///     );
+/// #   Ok(())
+/// # }
+/// ```
+pub fn write_jsonl<W: Write>(
+    observations: &Observations,
+    options: &ExportOptions,
+    mut writer: W,
+) -> Result<(), ReturnError> {
+    let mut write_object = |values: Vec<Value>| {
+        let object: Map<String, Value> = columns(observations, options.layout).into_iter()
+            .map(String::from)
+            .zip(values)
+            .collect();
+
+        writeln!(writer, "{}", Value::Object(object)).map_err(to_export_error)
+    };
+
+    match options.layout {
+        Layout::Long => for (date, series, value) in long_rows(observations) {
+            write_object(vec![
+                Value::String(options.format_date(date)?),
+                Value::String(series.to_string()),
+                to_json(value, options),
+            ])?;
+        },
+        Layout::Wide => for row in &observations.rows {
+            write_object(std::iter::once(Value::String(options.format_date(&row.date)?))
+                .chain(row.values.iter().map(|value| to_json(*value, options)))
+                .collect())?;
+        },
+    }
+
+    writer.flush().map_err(to_export_error)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Observation;
+
+    #[test]
+    fn should_write_an_object_for_each_line() {
+        let observations = Observations {
+            series: vec!["TP_DK_USD_A".to_string(), "TP_DK_EUR_A".to_string()],
+            rows: vec![Observation { date: "2011-12".to_string(), values: vec![Some(1.83651), None] }],
+        };
+
+        let mut export_options = ExportOptions::new();
+        export_options.change_layout(Layout::Wide);
+        export_options.change_precision(Some(2));
+
+        let mut output = Vec::new();
+
+        write_jsonl(&observations, &export_options, &mut output).unwrap();
+
+        assert_eq!(
+            "{\"date\":\"2011-12\",\"TP_DK_USD_A\":1.84,\"TP_DK_EUR_A\":null}\n",
+            String::from_utf8(output).unwrap()
+        );
+
+        export_options.change_layout(Layout::Long);
+        export_options.change_decimal_separator(DecimalSeparator::Comma);
+
+        let mut output = Vec::new();
+
+        write_jsonl(&observations, &export_options, &mut output).unwrap();
+
+        assert_eq!(
+            "{\"date\":\"2011-12\",\"series\":\"TP_DK_USD_A\",\"value\":\"1,84\"}\n\
+            {\"date\":\"2011-12\",\"series\":\"TP_DK_EUR_A\",\"value\":null}\n",
+            String::from_utf8(output).unwrap()
+        );
+    }
+}
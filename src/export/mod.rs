@@ -0,0 +1,229 @@
+/// writes observations as CSV.
+#[cfg(feature = "export_csv")]
+mod csv;
+/// writes observations as JSON Lines.
+#[cfg(feature = "export_jsonl")]
+mod jsonl;
+/// writes observations as Apache Parquet.
+#[cfg(feature = "export_parquet")]
+mod parquet;
+
+use crate::date::calendar::Day;
+use crate::error::ReturnError;
+use crate::response::Observations;
+
+#[cfg(feature = "export_csv")]
+pub use self::csv::write_csv;
+#[cfg(feature = "export_jsonl")]
+pub use self::jsonl::write_jsonl;
+#[cfg(feature = "export_parquet")]
+pub use self::parquet::write_parquet;
+
+
+/// is the shape of an exported table.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Layout {
+    /// has a row for each value of each series with "date", "series" and "value" columns.
+    Long,
+    /// has a row for each date with a "date" column and a column for each series.
+    Wide,
+}
+
+
+/// is the format of exported dates.
+#[derive(Debug, Clone, PartialEq)]
+pub enum DateFormat {
+    /// keeps dates as given by EVDS such as "13-12-2011", "2011-12" or "2011-Q4".
+    Evds,
+    /// writes the first day of each period in "year-month-day" format such as "2011-12-01".
+    Iso,
+    /// writes the first day of each period by replacing "%Y", "%m" and "%d" of the pattern with the year, month and day.
+    Pattern(String),
+}
+
+
+/// is the character separating the integer and fraction parts of exported values.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DecimalSeparator {
+    /// writes values such as "1.8365".
+    Point,
+    /// writes values such as "1,8365".
+    Comma,
+}
+
+
+/// contains the layout and the formatting of exported observations.
+///
+/// The default options give a long layout with dates as given by EVDS and values with a decimal point in full
+/// precision.
+///
+/// # Example
+///
+/// ```
+///     use tcmb_evds::export::{DateFormat, DecimalSeparator, ExportOptions, Layout};
+///
+///
+///     let mut export_options = ExportOptions::new();
+///
+///     export_options.change_layout(Layout::Wide);
+///     export_options.change_date_format(DateFormat::Pattern("%d.%m.%Y".to_string()));
+///     export_options.change_decimal_separator(DecimalSeparator::Comma);
+///     export_options.change_precision(Some(4));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportOptions {
+    layout: Layout,
+    date_format: DateFormat,
+    decimal_separator: DecimalSeparator,
+    precision: Option<usize>,
+}
+
+impl ExportOptions {
+    /// creates default options.
+    pub fn new() -> ExportOptions {
+        ExportOptions {
+            layout: Layout::Long,
+            date_format: DateFormat::Evds,
+            decimal_separator: DecimalSeparator::Point,
+            precision: None,
+        }
+    }
+
+    /// changes the layout of the exported table.
+    pub fn change_layout(&mut self, layout: Layout) {
+        self.layout = layout;
+    }
+
+    /// changes the format of exported dates.
+    pub fn change_date_format(&mut self, date_format: DateFormat) {
+        self.date_format = date_format;
+    }
+
+    /// changes the decimal separator of exported values.
+    pub fn change_decimal_separator(&mut self, decimal_separator: DecimalSeparator) {
+        self.decimal_separator = decimal_separator;
+    }
+
+    /// changes the number of fraction digits of exported values. `None` writes values in full precision.
+    pub fn change_precision(&mut self, precision: Option<usize>) {
+        self.precision = precision;
+    }
+
+    /// gives the layout of the exported table.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// formats a date of EVDS with the date format.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the date is not a date or a period of EVDS.
+    #[cfg_attr(not(any(feature = "export_csv", feature = "export_jsonl")), allow(dead_code))]
+    pub(crate) fn format_date(&self, date: &str) -> Result<String, ReturnError> {
+        let pattern = match &self.date_format {
+            DateFormat::Evds => return Ok(date.to_string()),
+            DateFormat::Iso => "%Y-%m-%d",
+            DateFormat::Pattern(pattern) => pattern.as_str(),
+        };
+
+        let day = Day::parse_period(date).ok_or(ReturnError::InvalidDate)?;
+
+        Ok(pattern
+            .replace("%Y", &format!("{:04}", day.year))
+            .replace("%m", &format!("{:02}", day.month))
+            .replace("%d", &format!("{:02}", day.day)))
+    }
+
+    /// gives the value rounded to the precision if there is.
+    #[cfg_attr(not(any(feature = "export_jsonl", feature = "export_parquet")), allow(dead_code))]
+    pub(crate) fn round(&self, value: f64) -> f64 {
+        match self.precision {
+            Some(precision) => format!("{:.*}", precision, value).parse().unwrap_or(value),
+            None => value,
+        }
+    }
+
+    /// formats the value with the precision and the decimal separator.
+    #[cfg_attr(not(any(feature = "export_csv", feature = "export_jsonl")), allow(dead_code))]
+    pub(crate) fn format_value(&self, value: f64) -> String {
+        let value = match self.precision {
+            Some(precision) => format!("{:.*}", precision, value),
+            None => value.to_string(),
+        };
+
+        match self.decimal_separator {
+            DecimalSeparator::Point => value,
+            DecimalSeparator::Comma => value.replace('.', ","),
+        }
+    }
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        ExportOptions::new()
+    }
+}
+
+
+/// gives the column names of the exported table.
+pub(crate) fn columns(observations: &Observations, layout: Layout) -> Vec<&str> {
+    match layout {
+        Layout::Long => vec!["date", "series", "value"],
+        Layout::Wide => std::iter::once("date").chain(observations.series.iter().map(String::as_str)).collect(),
+    }
+}
+
+/// gives the date, the series and the value of each cell of the observations in the long layout.
+pub(crate) fn long_rows(observations: &Observations) -> impl Iterator<Item = (&str, &str, Option<f64>)> {
+    observations.rows.iter().flat_map(move |row| {
+        observations.series.iter()
+            .zip(row.values.iter())
+            .map(move |(series, value)| (row.date.as_str(), series.as_str(), *value))
+    })
+}
+
+/// converts an input or output failure to an export error.
+pub(crate) fn to_export_error(error: impl std::fmt::Display) -> ReturnError {
+    ReturnError::ExportError(error.to_string())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Observation;
+
+    #[test]
+    fn should_format_dates_and_values() {
+        let mut export_options = ExportOptions::new();
+
+        assert_eq!(Ok("2011-Q4".to_string()), export_options.format_date("2011-Q4"));
+        assert_eq!("1.83651", export_options.format_value(1.83651));
+
+        export_options.change_date_format(DateFormat::Iso);
+        export_options.change_decimal_separator(DecimalSeparator::Comma);
+        export_options.change_precision(Some(2));
+
+        assert_eq!(Ok("2011-10-01".to_string()), export_options.format_date("2011-Q4"));
+        assert_eq!(Ok("2011-12-13".to_string()), export_options.format_date("13-12-2011"));
+        assert_eq!(Err(ReturnError::InvalidDate), export_options.format_date("2011-Q5"));
+        assert_eq!("1,84", export_options.format_value(1.83651));
+        assert_eq!(1.84, export_options.round(1.83651));
+
+        export_options.change_date_format(DateFormat::Pattern("%d.%m.%Y".to_string()));
+
+        assert_eq!(Ok("01.12.2011".to_string()), export_options.format_date("2011-12"));
+
+        let observations = Observations {
+            series: vec!["TP_DK_USD_A".to_string(), "TP_DK_EUR_A".to_string()],
+            rows: vec![Observation { date: "13-12-2011".to_string(), values: vec![Some(1.8365), None] }],
+        };
+
+        assert_eq!(vec!["date", "TP_DK_USD_A", "TP_DK_EUR_A"], columns(&observations, Layout::Wide));
+        assert_eq!(
+            vec![("13-12-2011", "TP_DK_USD_A", Some(1.8365)), ("13-12-2011", "TP_DK_EUR_A", None)],
+            long_rows(&observations).collect::<Vec<_>>()
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::io::Write;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Date32Array, Float64Array, RecordBatch, StringArray};
+use arrow_schema::{DataType, Field, Schema};
+use ::parquet::arrow::ArrowWriter;
+
+use super::{columns, long_rows, to_export_error, ExportOptions, Layout};
+use crate::date::calendar::Day;
+use crate::error::ReturnError;
+use crate::response::Observations;
+
+
+/// gives days since 01-01-1970 of the first day of the period of an EVDS date.
+fn to_date32(date: &str) -> Result<i32, ReturnError> {
+    let day = Day::parse_period(date).ok_or(ReturnError::InvalidDate)?;
+
+    Ok(day.to_days() as i32)
+}
+
+/// writes the observations as an Apache Parquet file in the layout of the options.
+///
+/// Dates are written as the `Date32` type holding the first day of each period and values as nullable `Float64`
+/// columns rounded to the precision if there is. Date formats and decimal separators of the options only apply to text
+/// formats.
+///
+/// This function is available with the `export_parquet` feature.
+///
+/// # Error
+///
+/// This function returns an error if the writer fails or a date is not a date or a period of EVDS.
+///
+/// # Example
+///
+/// ```no_run
+/// # use std::error::Error;
+///     use std::fs::File;
+///
+///     use tcmb_evds::common::ReturnFormat;
+///     use tcmb_evds::export::{self, ExportOptions, Layout};
+///     use tcmb_evds::response::Observations;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let response = r#"{"totalCount":1,"items":[{"Tarih":"13-12-2011","TP_DK_USD_A":"1.8365"}]}"#;
+///     let observations = Observations::parse(response, &ReturnFormat::Json)?;
+///
+///     let mut export_options = ExportOptions::new();
+///     export_options.change_layout(Layout::Wide);
+///
+///     export::write_parquet(&observations, &export_options, File::create("usd.parquet")?)?;
+/// #   Ok(())
+/// # }
+/// ```
+pub fn write_parquet<W: Write + Send>(
+    observations: &Observations,
+    options: &ExportOptions,
+    writer: W,
+) -> Result<(), ReturnError> {
+    let round = |value: Option<f64>| value.map(|value| options.round(value));
+
+    let arrays: Vec<ArrayRef> = match options.layout {
+        Layout::Long => {
+            let dates = long_rows(observations)
+                .map(|(date, _, _)| to_date32(date))
+                .collect::<Result<Vec<i32>, ReturnError>>()?;
+
+            vec![
+                Arc::new(Date32Array::from(dates)),
+                Arc::new(StringArray::from_iter_values(long_rows(observations).map(|(_, series, _)| series))),
+                Arc::new(long_rows(observations).map(|(_, _, value)| round(value)).collect::<Float64Array>()),
+            ]
+        },
+        Layout::Wide => {
+            let dates = observations.rows.iter()
+                .map(|row| to_date32(&row.date))
+                .collect::<Result<Vec<i32>, ReturnError>>()?;
+
+            std::iter::once(Arc::new(Date32Array::from(dates)) as ArrayRef)
+                .chain((0..observations.series.len()).map(|index| {
+                    Arc::new(observations.rows.iter().map(|row| round(row.values[index])).collect::<Float64Array>())
+                        as ArrayRef
+                }))
+                .collect()
+        },
+    };
+
+    let fields: Vec<Field> = columns(observations, options.layout).into_iter()
+        .zip(arrays.iter())
+        .map(|(column, array)| Field::new(column, array.data_type().clone(), *array.data_type() == DataType::Float64))
+        .collect();
+
+    let batch = RecordBatch::try_new(Arc::new(Schema::new(fields)), arrays).map_err(to_export_error)?;
+
+    let mut writer = ArrowWriter::try_new(writer, batch.schema(), None).map_err(to_export_error)?;
+
+    writer.write(&batch).map_err(to_export_error)?;
+    writer.close().map_err(to_export_error)?;
+
+    Ok(())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Observation;
+
+    #[test]
+    fn should_write_readable_parquet() {
+        use ::parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let observations = Observations {
+            series: vec!["TP_FG_J0".to_string()],
+            rows: vec![
+                Observation { date: "2021-1".to_string(), values: vec![Some(1.68)] },
+                Observation { date: "2021-2".to_string(), values: vec![None] },
+            ],
+        };
+
+        let mut export_options = ExportOptions::new();
+        export_options.change_layout(Layout::Wide);
+
+        let path = std::env::temp_dir().join(format!("tcmb_evds_export_{}.parquet", std::process::id()));
+
+        write_parquet(&observations, &export_options, std::fs::File::create(&path).unwrap()).unwrap();
+
+        let reader = SerializedFileReader::new(std::fs::File::open(&path).unwrap()).unwrap();
+        let schema = reader.metadata().file_metadata().schema_descr();
+
+        assert_eq!(2, reader.metadata().file_metadata().num_rows());
+        assert_eq!("date", schema.column(0).name());
+        assert_eq!("TP_FG_J0", schema.column(1).name());
+
+        assert_eq!(Err(ReturnError::InvalidDate), to_date32("2021-13"));
+        assert_eq!(Ok(18_628), to_date32("2021-1"));
+
+        std::fs::remove_file(path).unwrap();
+    }
+}
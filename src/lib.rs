@@ -292,9 +292,40 @@ pub mod evds_basic;
 /// [`get_advanced_data`]: crate::evds_currency::CurrencySeries::get_advanced_data
 /// [`get_multiple_data`]: crate::evds_currency::MultipleCurrencySeries::get_multiple_data
 pub mod evds_currency;
+/// checks series against their declared frequencies and the Turkish business calendar.
+///
+/// [`check`](fn@crate::completeness::check) lists missing periods, duplicate dates and runs of empty values of a series
+/// in [`Observations`](struct@crate::response::Observations) over a [`DateRange`](struct@crate::date::DateRange). The
+/// declared frequency of a series is given by [`get_series_metadata`](fn@crate::evds_basic::get_series_metadata).
+pub mod completeness;
+/// mirrors data series into a local SQLite database and keeps them up to date with incremental requests.
+///
+/// This module is available with the `store` feature.
+#[cfg(feature = "store")]
+pub mod store;
+/// contains the typed form of data series responses.
+///
+/// [`Observations`](struct@crate::response::Observations) is parsed from a response of any
+/// [`ReturnFormat`](enum@crate::common::ReturnFormat) and gives dates and numeric values of each series, so that the
+/// data can be processed without handling JSON, CSV or XML texts. With the `dataframe` and `arrow` features, observations
+/// are converted to Polars data frames and Arrow record batches respectively. Exact observations of the `decimal` feature
+/// are converted to data frames with `Decimal` columns.
+pub mod response;
+/// writes [`Observations`](struct@crate::response::Observations) to files that BI tools consume directly.
+///
+/// Each format is available with its own feature: `export_csv` for CSV, `export_jsonl` for JSON Lines and
+/// `export_parquet` for Apache Parquet. [`ExportOptions`](struct@crate::export::ExportOptions) chooses between long and
+/// wide layouts and formats dates and values.
+#[cfg(any(feature = "export_csv", feature = "export_jsonl", feature = "export_parquet"))]
+pub mod export;
 mod traits;
 
 #[cfg(feature = "async_mode")]
 mod request_async;
 #[cfg(feature = "sync_mode")]
 mod request_sync;
+
+mod series_split;
+
+#[cfg(test)]
+mod stub_server;
@@ -1,13 +1,18 @@
+#[cfg(feature = "async_mode")]
+use std::time::Instant;
+
 #[cfg(feature = "async_mode")]
 use curl::easy::{Easy2, Handler, WriteError};
 
+#[cfg(feature = "async_mode")]
+use crate::common::{ClientConfig, ErrorClass, Transport};
 #[cfg(feature = "async_mode")]
 use crate::error::ReturnError;
 
 
 // TESTED
 #[cfg(feature = "async_mode")]
-struct Collector(Vec<u8>);
+pub(crate) struct Collector(pub(crate) Vec<u8>);
 
 #[cfg(feature = "async_mode")]
 impl Handler for Collector {
@@ -18,48 +23,245 @@ impl Handler for Collector {
 }
 
 
+/// applies timeouts, proxy, CA bundle, user agent and HTTP version settings to the handle.
+#[cfg(feature = "async_mode")]
+fn configure(handle: &mut Easy2<Collector>, client_config: &ClientConfig) -> Result<(), curl::Error> {
+    if let Some(connect_timeout) = client_config.get_connect_timeout() {
+        handle.connect_timeout(connect_timeout)?;
+    }
+    if let Some(read_timeout) = client_config.get_read_timeout() {
+        handle.low_speed_limit(1)?;
+        handle.low_speed_time(read_timeout)?;
+    }
+    if let Some(total_timeout) = client_config.get_total_timeout() {
+        handle.timeout(total_timeout)?;
+    }
+    if let Some(proxy) = client_config.get_proxy() {
+        handle.proxy(proxy)?;
+    }
+    if let Some((user_name, password)) = client_config.get_proxy_credentials() {
+        handle.proxy_username(user_name)?;
+        handle.proxy_password(password)?;
+    }
+    if let Some(ca_bundle) = client_config.get_ca_bundle() {
+        handle.cainfo(ca_bundle)?;
+    }
+
+    handle.useragent(client_config.get_user_agent())?;
+    handle.http_version(client_config.get_http_version().to_curl())?;
+
+    Ok(())
+}
+
 /// requests required data from server via given url in async mode.
 ///
-/// This function is fundamental and at the bottom level of the requesting hierarchy.  
+/// This function is fundamental and at the bottom level of the requesting hierarchy. The curl handle is taken from the
+/// connection pool and given back afterwards, so that kept alive connections are reused by the following requests.
 #[cfg(feature = "async_mode")]
-pub(crate) fn do_request(url_format: &str) -> Result<String, ReturnError> {
-    let mut handle = Easy2::new(Collector(Vec::new()));
-    
-    if let Err(_) = handle.get(true) {
+pub(crate) fn do_request(url_format: &str, transport: &Transport) -> Result<String, ReturnError> {
+    let connection_pool = &transport.connection_pool;
+
+    let mut handle = connection_pool.take_async_handle();
+
+    handle.reset();
+    handle.get_mut().0.clear();
+
+    let result = perform_request(&mut handle, url_format, transport);
+
+    handle.get_mut().0.clear();
+    connection_pool.give_back_async_handle(handle, transport.client_config.get_max_idle_connections());
+
+    result
+}
+
+#[cfg(feature = "async_mode")]
+fn perform_request(
+    handle: &mut Easy2<Collector>,
+    url_format: &str,
+    transport: &Transport
+) -> Result<String, ReturnError> {
+    let retry_policy = &transport.retry_policy;
+
+    if handle.get(true).is_err() {
         return Err(ReturnError::UnableToRequest)
     }
-    if let Err(_) = handle.url(url_format) {
+    if configure(handle, &transport.client_config).is_err() {
+        return Err(ReturnError::UnableToConfigureClient);
+    }
+    if handle.url(url_format).is_err() {
         return Err(ReturnError::UnableToSetUrl);
     }
 
-    
-    // Applying request is repeated 3 times if the operation does not work properly. In the last turn if the perform()
-    // function ends up with an error, an error is returned from the loop. Otherwise, successful operation breaks the 
-    // loop.
-    let mut perform_result;
 
-    for element in 0..3 {
-        perform_result = handle.perform();
+    // Applying request is repeated as long as the retry policy allows. Failed transfers and retryable status codes
+    // end up with waiting for the next attempt. When the policy gives up, the error of the last attempt is returned.
+    let started = Instant::now();
+    let mut attempt = 0;
 
-        if perform_result.is_ok() { break; }
+    loop {
+        attempt += 1;
+        transport.wait_for_rate_limit();
+        handle.get_mut().0.clear();
 
-        if element != 2 { continue; }
+        let perform_result = handle.perform();
 
-        return Err(ReturnError::FailedToApplyRequest);
-    }
+        transport.connection_pool.record_transfer(handle.num_connects().unwrap_or(0));
 
+        if let Err(error) = perform_result {
+            if retry_policy.wait_for_next_attempt(attempt, started, ErrorClass::from_curl_error(&error)) { continue; }
+
+            return Err(ReturnError::FailedToApplyRequest);
+        }
+
+        match handle.response_code() {
+            Ok(200) => break,
+            Ok(number) => {
+                if retry_policy.wait_for_next_attempt(attempt, started, ErrorClass::from_response_code(number)) {
+                    continue;
+                }
 
-    match handle.response_code() {
-        Ok(number) => {
-            if number != 200 {
                 return Err(ReturnError::RequestDenied)
-            }
-        },
-        Err(_) => return Err(ReturnError::NotFound),
+            },
+            Err(_) => return Err(ReturnError::NotFound),
+        }
     }
 
     let contents = handle.get_ref();
     let response = String::from_utf8_lossy(&contents.0);
-    
+
     Ok(response.to_string())
 }
+
+
+#[cfg(test)]
+#[cfg(feature = "async_mode")]
+mod tests {
+    use std::net::TcpListener;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::common::{ConnectionStats, RateLimiter, RetryPolicy};
+    use crate::stub_server::StubServer;
+
+    fn quick_transport(max_attempts: u32) -> Transport {
+        let mut transport = Transport::new();
+        transport.retry_policy = RetryPolicy::from(max_attempts, Duration::from_millis(5));
+
+        transport
+    }
+
+    #[test]
+    fn should_retry_flaky_server() {
+        let server = StubServer::start(vec![(503, ""), (500, ""), (200, "data")]);
+
+        let response = do_request(&server.url(), &quick_transport(3));
+
+        assert_eq!(Ok("data".to_string()), response);
+        assert_eq!(3, server.request_count());
+    }
+
+    #[test]
+    fn should_give_up_after_max_attempts() {
+        let server = StubServer::start(vec![(503, ""), (503, ""), (200, "data")]);
+
+        let response = do_request(&server.url(), &quick_transport(2));
+
+        assert_eq!(Err(ReturnError::RequestDenied), response);
+        assert_eq!(2, server.request_count());
+    }
+
+    #[test]
+    fn should_not_retry_client_errors() {
+        let server = StubServer::start(vec![(404, ""), (200, "data")]);
+
+        let response = do_request(&server.url(), &quick_transport(3));
+
+        assert_eq!(Err(ReturnError::RequestDenied), response);
+        assert_eq!(1, server.request_count());
+    }
+
+    #[test]
+    fn should_not_retry_excluded_error_classes() {
+        let server = StubServer::start(vec![(429, ""), (200, "data")]);
+
+        let mut transport = quick_transport(3);
+        transport.retry_policy.exclude_error_class(ErrorClass::TooManyRequests);
+
+        assert_eq!(Err(ReturnError::RequestDenied), do_request(&server.url(), &transport));
+        assert_eq!(1, server.request_count());
+    }
+
+    #[test]
+    fn should_send_request_through_proxy() {
+        let server = StubServer::start(vec![(200, "proxied")]);
+
+        let mut transport = quick_transport(1);
+        transport.client_config.change_proxy(Some(&server.url()));
+
+        let response = do_request("http://evds.invalid/series=TP.DK.USD.A", &transport);
+
+        assert_eq!(Ok("proxied".to_string()), response);
+        assert_eq!(vec!["http://evds.invalid/series=TP.DK.USD.A".to_string()], server.requested_paths());
+    }
+
+    #[test]
+    fn should_time_out_silent_server() {
+        // The listener is never accepted, so the connection is established but no response arrives.
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/", listener.local_addr().unwrap());
+
+        let mut transport = quick_transport(1);
+        transport.client_config.change_total_timeout(Some(Duration::from_millis(200)));
+
+        let started = Instant::now();
+        let response = do_request(&url, &transport);
+
+        assert_eq!(Err(ReturnError::FailedToApplyRequest), response);
+        assert!(started.elapsed() < Duration::from_secs(5));
+    }
+
+    #[test]
+    fn should_reuse_kept_alive_connection() {
+        let server = StubServer::start(vec![(200, "data")]);
+        let transport = quick_transport(1);
+
+        for _ in 0..5 {
+            assert_eq!(Ok("data".to_string()), do_request(&server.url(), &transport));
+        }
+
+        assert_eq!(5, server.request_count());
+        assert_eq!(1, server.connection_count());
+        assert_eq!(ConnectionStats { requests: 5, new_connections: 1 }, transport.connection_pool.stats());
+    }
+
+    #[test]
+    fn should_open_new_connections_without_reuse() {
+        let server = StubServer::start(vec![(200, "data")]);
+
+        let mut transport = quick_transport(1);
+        transport.client_config.change_max_idle_connections(0);
+
+        for _ in 0..3 {
+            assert_eq!(Ok("data".to_string()), do_request(&server.url(), &transport));
+        }
+
+        assert_eq!(3, server.connection_count());
+        assert_eq!(ConnectionStats { requests: 3, new_connections: 3 }, transport.connection_pool.stats());
+    }
+
+    #[test]
+    fn should_pass_each_attempt_through_rate_limiter() {
+        let server = StubServer::start(vec![(503, ""), (200, "data")]);
+
+        let mut transport = quick_transport(2);
+        transport.rate_limiter = Some(RateLimiter::from(10.0, 1).unwrap());
+
+        let started = Instant::now();
+
+        assert_eq!(Ok("data".to_string()), do_request(&server.url(), &transport));
+        assert_eq!(Ok("data".to_string()), do_request(&server.url(), &transport));
+
+        // 3 attempts with a single initial token require at least 2 / 10 seconds.
+        assert!(started.elapsed() >= Duration::from_millis(190));
+    }
+}
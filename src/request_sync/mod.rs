@@ -1,53 +1,194 @@
+#[cfg(feature = "sync_mode")]
+use std::time::Instant;
+
 #[cfg(feature = "sync_mode")]
 use curl::easy::Easy;
 
+#[cfg(feature = "sync_mode")]
+use crate::common::{ClientConfig, ErrorClass, Transport};
 #[cfg(feature = "sync_mode")]
 use crate::error::ReturnError;
 
 
+/// applies timeouts, proxy, CA bundle, user agent and HTTP version settings to the handle.
+#[cfg(feature = "sync_mode")]
+fn configure(handle: &mut Easy, client_config: &ClientConfig) -> Result<(), curl::Error> {
+    if let Some(connect_timeout) = client_config.get_connect_timeout() {
+        handle.connect_timeout(connect_timeout)?;
+    }
+    if let Some(read_timeout) = client_config.get_read_timeout() {
+        handle.low_speed_limit(1)?;
+        handle.low_speed_time(read_timeout)?;
+    }
+    if let Some(total_timeout) = client_config.get_total_timeout() {
+        handle.timeout(total_timeout)?;
+    }
+    if let Some(proxy) = client_config.get_proxy() {
+        handle.proxy(proxy)?;
+    }
+    if let Some((user_name, password)) = client_config.get_proxy_credentials() {
+        handle.proxy_username(user_name)?;
+        handle.proxy_password(password)?;
+    }
+    if let Some(ca_bundle) = client_config.get_ca_bundle() {
+        handle.cainfo(ca_bundle)?;
+    }
+
+    handle.useragent(client_config.get_user_agent())?;
+    handle.http_version(client_config.get_http_version().to_curl())?;
+
+    Ok(())
+}
+
 /// requests required data from server via given url in sync mode.
 ///
-/// This function is fundamental and at the bottom level of the requesting hierarchy.  
+/// This function is fundamental and at the bottom level of the requesting hierarchy. The curl handle is taken from the
+/// connection pool and given back afterwards, so that kept alive connections are reused by the following requests.
 #[cfg(feature = "sync_mode")]
-pub(crate) fn do_request(url_format: &str) -> Result<String, ReturnError> {
+pub(crate) fn do_request(url_format: &str, transport: &Transport) -> Result<String, ReturnError> {
+    let connection_pool = &transport.connection_pool;
+
+    let mut handle = connection_pool.take_sync_handle();
+
+    handle.reset();
+
+    let result = perform_request(&mut handle, url_format, transport);
+
+    connection_pool.give_back_sync_handle(handle, transport.client_config.get_max_idle_connections());
+
+    result
+}
+
+#[cfg(feature = "sync_mode")]
+fn perform_request(
+    handle: &mut Easy,
+    url_format: &str,
+    transport: &Transport
+) -> Result<String, ReturnError> {
+    let retry_policy = &transport.retry_policy;
+
     let mut buf = Vec::new();
-    let mut handle = Easy::new();
 
-    if let Err(_) = handle.url(url_format) {
+    if handle.url(url_format).is_err() {
         return Err(ReturnError::UnableToSetUrl);
     }
+    if configure(handle, &transport.client_config).is_err() {
+        return Err(ReturnError::UnableToConfigureClient);
+    }
 
-    {
-        let mut transfer = handle.transfer();
-        if let Err(_) = transfer.write_function(|data| {
-            buf.extend_from_slice(data);
-            Ok(data.len())
-        }) {
-            return Err(ReturnError::FailedToSaveReceivedData);
-        }
+    // Applying request is repeated as long as the retry policy allows. Failed transfers and retryable status codes
+    // end up with waiting for the next attempt. When the policy gives up, the error of the last attempt is returned.
+    // A non retryable status code keeps the received body as the response.
+    let started = Instant::now();
+    let mut attempt = 0;
+
+    loop {
+        attempt += 1;
+        transport.wait_for_rate_limit();
+        buf.clear();
 
-        
-        // Applying request is repeated 3 times if the operation does not work properly. In the last turn if the 
-        // perform() function ends up with an error, an error is returned from the loop. Otherwise, successful operation 
-        // breaks the loop.
-        let mut perform_result;
+        let perform_result = {
+            let mut transfer = handle.transfer();
+            let write_result = transfer.write_function(|data| {
+                buf.extend_from_slice(data);
+                Ok(data.len())
+            });
+            if write_result.is_err() {
+                return Err(ReturnError::FailedToSaveReceivedData);
+            }
 
-        for element in 0..3 {
-            perform_result = transfer.perform();
+            transfer.perform()
+        };
 
-            if perform_result.is_ok() { break; }
+        transport.connection_pool.record_transfer(handle.num_connects().unwrap_or(0));
+
+        if let Err(error) = perform_result {
+            if retry_policy.wait_for_next_attempt(attempt, started, ErrorClass::from_curl_error(&error)) { continue; }
 
-            if element != 2 { continue; }
-            
             return Err(ReturnError::FailedToApplyRequest);
         }
+
+        let error_class = match handle.response_code() {
+            Ok(number) => ErrorClass::from_response_code(number),
+            Err(_) => None,
+        };
+
+        if error_class.is_none() { break; }
+
+        if retry_policy.wait_for_next_attempt(attempt, started, error_class) { continue; }
+
+        return Err(ReturnError::RequestDenied);
     }
 
     let response = String::from_utf8_lossy(&buf);
-    
+
     if response.is_empty() {
         return Err(ReturnError::NotFound);
     }
 
     Ok(response.to_string())
 }
+
+
+#[cfg(test)]
+#[cfg(feature = "sync_mode")]
+mod tests {
+    use std::time::Duration;
+
+    use super::*;
+    use crate::common::RetryPolicy;
+    use crate::stub_server::StubServer;
+
+    fn quick_transport(max_attempts: u32) -> Transport {
+        let mut transport = Transport::new();
+        transport.retry_policy = RetryPolicy::from(max_attempts, Duration::from_millis(5));
+
+        transport
+    }
+
+    #[test]
+    fn should_retry_flaky_server() {
+        let server = StubServer::start(vec![(503, ""), (429, ""), (200, "data")]);
+
+        let response = do_request(&server.url(), &quick_transport(3));
+
+        assert_eq!(Ok("data".to_string()), response);
+        assert_eq!(3, server.request_count());
+    }
+
+    #[test]
+    fn should_not_retry_client_errors() {
+        let server = StubServer::start(vec![(404, "missing"), (200, "data")]);
+
+        let response = do_request(&server.url(), &quick_transport(3));
+
+        assert_eq!(Ok("missing".to_string()), response);
+        assert_eq!(1, server.request_count());
+    }
+
+    #[test]
+    fn should_send_request_through_proxy() {
+        let server = StubServer::start(vec![(200, "proxied")]);
+
+        let mut transport = quick_transport(1);
+        transport.client_config.change_proxy(Some(&server.url()));
+
+        let response = do_request("http://evds.invalid/series=TP.DK.USD.A", &transport);
+
+        assert_eq!(Ok("proxied".to_string()), response);
+        assert_eq!(vec!["http://evds.invalid/series=TP.DK.USD.A".to_string()], server.requested_paths());
+    }
+
+    #[test]
+    fn should_reuse_kept_alive_connection() {
+        let server = StubServer::start(vec![(200, "data")]);
+        let transport = quick_transport(1);
+
+        for _ in 0..5 {
+            assert_eq!(Ok("data".to_string()), do_request(&server.url(), &transport));
+        }
+
+        assert_eq!(1, server.connection_count());
+        assert_eq!(5, transport.connection_pool.stats().requests);
+    }
+}
@@ -0,0 +1,132 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use arrow_array::{ArrayRef, Date32Array, Float64Array, RecordBatch};
+use arrow_schema::{DataType, Field, Schema};
+
+use super::observations::series_name;
+use super::{Observations, SeriesMetadata};
+use crate::date::calendar::Day;
+use crate::error::ReturnError;
+
+
+/// gives the metadata of a series column, where unknown entries are empty so that each column has the same keys.
+fn field_metadata(series: &str, metadata: Option<&SeriesMetadata>) -> HashMap<String, String> {
+    let entry = |value: Option<&Option<String>>| value.and_then(Option::clone).unwrap_or_default();
+
+    let mut field_metadata = HashMap::new();
+
+    field_metadata.insert("name".to_string(), entry(metadata.map(|metadata| &metadata.name)));
+    field_metadata.insert("name_eng".to_string(), entry(metadata.map(|metadata| &metadata.name_eng)));
+    field_metadata.insert("unit".to_string(), entry(metadata.map(|metadata| &metadata.unit)));
+    field_metadata.insert("frequency".to_string(), entry(metadata.map(|metadata| &metadata.frequency)));
+    field_metadata.insert("series".to_string(), series.to_string());
+
+    field_metadata
+}
+
+impl Observations {
+    /// converts the observations to an Arrow `RecordBatch` with a "date" column and a column for each series.
+    ///
+    /// The "date" column has the `Date32` type holding the first day of each period and series columns have the
+    /// nullable `Float64` type. A series column is named by the code of its metadata if the metadata of the series is
+    /// given, otherwise as in [`series`](field@Observations::series).
+    ///
+    /// Each series column keeps "series", "name", "name_eng", "unit" and "frequency" entries in its metadata. The
+    /// entries come from the given [`SeriesMetadata`](struct@SeriesMetadata), which is given by
+    /// [`get_series_metadata`](fn@crate::evds_basic::get_series_metadata), and they are empty when unknown. The schema
+    /// keeps the column names of the series in its "series" entry separated by commas.
+    ///
+    /// This function is available with the `arrow` feature.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if a date is not a date or a period of EVDS.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::error::Error;
+    ///     use tcmb_evds::common::ReturnFormat;
+    ///     use tcmb_evds::response::{Observations, SeriesMetadata};
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///     let response = r#"{"totalCount":1,"items":[{"Tarih":"2021-1","TP_FG_J0":"1.68"}]}"#;
+    ///     let series_list = r#"[{"SERIE_CODE":"TP.FG.J0","FREQUENCY_STR":"AYLIK"}]"#;
+    ///
+    ///     let observations = Observations::parse(response, &ReturnFormat::Json)?;
+    ///     let metadata = SeriesMetadata::parse_list(series_list, &ReturnFormat::Json)?;
+    ///
+    ///     let record_batch = observations.to_record_batch(&metadata)?;
+    ///
+    ///     assert_eq!("AYLIK", record_batch.schema().field(1).metadata()["frequency"]);
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn to_record_batch(&self, metadata: &[SeriesMetadata]) -> Result<RecordBatch, ReturnError> {
+        let days = self.rows.iter()
+            .map(|row| Day::parse_period(&row.date).map(|day| day.to_days() as i32).ok_or(ReturnError::InvalidDate))
+            .collect::<Result<Vec<i32>, ReturnError>>()?;
+
+        let mut fields = vec![Field::new("date", DataType::Date32, false)];
+        let mut columns: Vec<ArrayRef> = vec![Arc::new(Date32Array::from(days))];
+
+        for (index, name) in self.series.iter().enumerate() {
+            let series_metadata = metadata.iter().find(|series_metadata| series_name(&series_metadata.code) == *name);
+            let column_name = series_metadata.map(|series_metadata| series_metadata.code.trim()).unwrap_or(name);
+
+            fields.push(
+                Field::new(column_name, DataType::Float64, true)
+                    .with_metadata(field_metadata(column_name, series_metadata))
+            );
+            columns.push(Arc::new(self.rows.iter().map(|row| row.values[index]).collect::<Float64Array>()));
+        }
+
+        let series_names: Vec<&str> = fields[1..].iter().map(|field| field.name().as_str()).collect();
+        let schema_metadata = HashMap::from([("series".to_string(), series_names.join(","))]);
+
+        let schema = Schema::new(fields).with_metadata(schema_metadata);
+
+        RecordBatch::try_new(Arc::new(schema), columns).map_err(|error| ReturnError::ExportError(error.to_string()))
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::response::Observation;
+
+    #[test]
+    fn should_keep_a_stable_schema() {
+        let observations = Observations {
+            series: vec!["TP_FG_J0".to_string(), "TP_FG_J1".to_string()],
+            rows: vec![
+                Observation { date: "2021-1".to_string(), values: vec![Some(1.68), None] },
+                Observation { date: "2021-2".to_string(), values: vec![Some(0.91), Some(1.2)] },
+            ],
+        };
+        let metadata = SeriesMetadata {
+            code: "TP.FG.J0".to_string(),
+            data_group: None,
+            name: Some("TÜFE".to_string()),
+            name_eng: None,
+            unit: Some("%".to_string()),
+            frequency: Some("AYLIK".to_string()),
+            start_date: None,
+            end_date: None,
+        };
+
+        let record_batch = observations.to_record_batch(&[metadata]).unwrap();
+        let schema = record_batch.schema();
+
+        assert_eq!(&DataType::Date32, schema.field(0).data_type());
+        assert_eq!("TP.FG.J0", schema.field(1).name());
+        assert_eq!("TP_FG_J1", schema.field(2).name());
+        assert_eq!("%", schema.field(1).metadata()["unit"]);
+        assert_eq!("", schema.field(2).metadata()["frequency"]);
+        assert_eq!("TP.FG.J0,TP_FG_J1", schema.metadata()["series"]);
+        assert_eq!(1, record_batch.column(2).null_count());
+        assert_eq!(18_659, record_batch.column(0).as_any().downcast_ref::<Date32Array>().unwrap().value(1));
+    }
+}
@@ -0,0 +1,79 @@
+use super::Table;
+use crate::error::ReturnError;
+
+
+/// splits a CSV line into fields by taking double quoted fields into account.
+fn split_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut characters = line.chars().peekable();
+
+    while let Some(character) = characters.next() {
+        match character {
+            '"' if quoted && characters.peek() == Some(&'"') => {
+                field.push('"');
+                characters.next();
+            },
+            '"' => quoted = !quoted,
+            ',' if !quoted => fields.push(std::mem::take(&mut field)),
+            _ => field.push(character),
+        }
+    }
+
+    fields.push(field);
+
+    fields
+}
+
+/// quotes a field if it contains a separator, a quote or a line break.
+fn quote_field(field: &str) -> String {
+    if field.contains(',') || field.contains('"') || field.contains('\n') {
+        return format!("\"{}\"", field.replace('"', "\"\""));
+    }
+
+    field.to_string()
+}
+
+/// parses a CSV response whose first line is the header.
+///
+/// # Error
+///
+/// This function returns an error if the response does not have a header.
+pub(crate) fn parse(response: &str) -> Result<Table, ReturnError> {
+    let mut lines = response.lines().filter(|line| !line.trim().is_empty());
+
+    let columns = match lines.next() {
+        Some(header) => split_line(header.trim_start_matches('\u{feff}')),
+        None => return Err(ReturnError::UnableToParseResponse),
+    };
+
+    let rows = lines.map(|line| {
+        let mut fields = split_line(line);
+        fields.resize(columns.len(), String::new());
+
+        fields.into_iter().map(|field| if field.is_empty() { None } else { Some(field) }).collect()
+    }).collect();
+
+    Ok(Table { columns, rows })
+}
+
+/// renders the table with a header line and a line for each row.
+pub(crate) fn render(table: &Table) -> String {
+    let mut output = String::new();
+
+    let header: Vec<String> = table.columns.iter().map(|column| quote_field(column)).collect();
+    output.push_str(&header.join(","));
+    output.push('\n');
+
+    for row in &table.rows {
+        let fields: Vec<String> = row.iter()
+            .map(|cell| cell.as_deref().map(quote_field).unwrap_or_default())
+            .collect();
+
+        output.push_str(&fields.join(","));
+        output.push('\n');
+    }
+
+    output
+}
@@ -0,0 +1,188 @@
+use polars::prelude::{Column, DataFrame, IntoColumn, IntoSeries, Int32Chunked, NamedFrom, Series};
+#[cfg(feature = "decimal")]
+use polars::prelude::Int128Chunked;
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+use super::observations::series_name;
+use super::Observations;
+use crate::date::calendar::Day;
+use crate::error::ReturnError;
+
+
+impl Observations {
+    /// converts the observations to a Polars `DataFrame` with a "date" column and a column for each series.
+    ///
+    /// The "date" column has the `Date` type holding the first day of each period and series columns have the `Float64`
+    /// type, where missing values are nulls. Series columns are named as in [`series`](field@Observations::series).
+    /// [`get_data_frame`](fn@crate::evds_basic::get_data_frame) names them by the requested series codes instead.
+    ///
+    /// This function is available with the `dataframe` feature.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if a date is not a date or a period of EVDS.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::error::Error;
+    ///     use tcmb_evds::common::ReturnFormat;
+    ///     use tcmb_evds::response::Observations;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///     let response = r#"{"totalCount":1,"items":[{"Tarih":"13-12-2011","TP_DK_USD_A":"1.8365"}]}"#;
+    ///
+    ///     let data_frame = Observations::parse(response, &ReturnFormat::Json)?.to_polars()?;
+    ///
+    ///     assert_eq!((1, 2), data_frame.shape());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn to_polars(&self) -> Result<DataFrame, ReturnError> {
+        self.to_polars_named(&[])
+    }
+
+    /// converts the observations to a Polars `DataFrame` whose series columns are named by the given series codes.
+    ///
+    /// Series that do not match any of the codes keep their names.
+    pub(crate) fn to_polars_named(&self, series_codes: &[&str]) -> Result<DataFrame, ReturnError> {
+        build_frame(self, series_codes, |name, values| Ok(Series::new(name.into(), values).into_column()))
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Observations<Decimal> {
+    /// converts the exact observations to a Polars `DataFrame` with a "date" column and a `Decimal` column for each
+    /// series.
+    ///
+    /// The scale of each series column is the largest scale of its values, so that no value is rounded. Dates, nulls
+    /// and names are given as in [`to_polars`](fn@Observations::to_polars).
+    ///
+    /// This function is available with both of the `dataframe` and `decimal` features.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if a date is not a date or a period of EVDS.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::error::Error;
+    ///     use tcmb_evds::common::ReturnFormat;
+    ///     use tcmb_evds::response::Observations;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///     let response = "Tarih,TP_DK_USD_A\n13-12-2011,1.8365\n";
+    ///
+    ///     let data_frame = Observations::parse_decimal(response, &ReturnFormat::Csv)?.to_polars()?;
+    ///
+    ///     assert_eq!((1, 2), data_frame.shape());
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn to_polars(&self) -> Result<DataFrame, ReturnError> {
+        self.to_polars_named(&[])
+    }
+
+    /// converts the exact observations to a Polars `DataFrame` whose series columns are named by the given series
+    /// codes.
+    pub(crate) fn to_polars_named(&self, series_codes: &[&str]) -> Result<DataFrame, ReturnError> {
+        build_frame(self, series_codes, |name, values| {
+            let scale = values.iter().flatten().map(|value| value.scale()).max().unwrap_or(0);
+
+            let mantissas: Int128Chunked = values.into_iter()
+                .map(|value| value.map(|mut value| {
+                    value.rescale(scale);
+                    value.mantissa()
+                }))
+                .collect();
+
+            let column = mantissas.with_name(name.into())
+                .into_decimal(None, scale as usize)
+                .map_err(|error| ReturnError::ExportError(error.to_string()))?;
+
+            Ok(column.into_series().into_column())
+        })
+    }
+}
+
+
+/// builds a data frame with the "date" column and a series column made by the given function from the name and values
+/// of each series.
+fn build_frame<T, F>(
+    observations: &Observations<T>,
+    series_codes: &[&str],
+    series_column: F,
+) -> Result<DataFrame, ReturnError>
+where
+    T: Copy,
+    F: Fn(&str, Vec<Option<T>>) -> Result<Column, ReturnError>,
+{
+    let days = observations.rows.iter()
+        .map(|row| Day::parse_period(&row.date).map(|day| day.to_days() as i32).ok_or(ReturnError::InvalidDate))
+        .collect::<Result<Vec<i32>, ReturnError>>()?;
+
+    let date_column = Int32Chunked::from_vec("date".into(), days).into_date().into_series().into_column();
+
+    let series_columns = observations.series.iter().enumerate().map(|(index, name)| {
+        let column_name = series_codes.iter()
+            .find(|code| series_name(code) == *name)
+            .map(|code| code.trim())
+            .unwrap_or(name);
+
+        series_column(column_name, observations.rows.iter().map(|row| row.values[index]).collect())
+    });
+
+    let columns = std::iter::once(Ok(date_column))
+        .chain(series_columns)
+        .collect::<Result<Vec<Column>, ReturnError>>()?;
+
+    DataFrame::new(columns).map_err(|error| ReturnError::ExportError(error.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use polars::prelude::DataType;
+
+    use super::*;
+    use crate::response::Observation;
+
+    #[test]
+    fn should_keep_dates_and_nulls() {
+        let observations = Observations {
+            series: vec!["TP_FG_J0".to_string(), "TP_FG_J1".to_string()],
+            rows: vec![
+                Observation { date: "2021-1".to_string(), values: vec![Some(1.68), None] },
+                Observation { date: "2021-2".to_string(), values: vec![Some(0.91), Some(1.2)] },
+            ],
+        };
+
+        let data_frame = observations.to_polars_named(&["TP.FG.J0"]).unwrap();
+
+        assert_eq!(vec!["date", "TP.FG.J0", "TP_FG_J1"], data_frame.get_column_names_str());
+        assert_eq!(&DataType::Date, data_frame.column("date").unwrap().dtype());
+        assert_eq!(1, data_frame.column("TP_FG_J1").unwrap().null_count());
+        assert_eq!(Some(18_659), data_frame.column("date").unwrap().date().unwrap().phys.get(1));
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn should_keep_exact_decimals() {
+        let observations = Observations {
+            series: vec!["TP_DK_USD_A".to_string()],
+            rows: vec![
+                Observation { date: "13-12-2011".to_string(), values: vec![Some(Decimal::new(18365, 4))] },
+                Observation { date: "14-12-2011".to_string(), values: vec![None] },
+                Observation { date: "15-12-2011".to_string(), values: vec![Some(Decimal::new(184, 2))] },
+            ],
+        };
+
+        let data_frame = observations.to_polars_named(&["TP.DK.USD.A"]).unwrap();
+        let column = data_frame.column("TP.DK.USD.A").unwrap();
+
+        assert_eq!(&DataType::Decimal(None, Some(4)), column.dtype());
+        assert_eq!(1, column.null_count());
+        assert_eq!(Some(18_400), column.decimal().unwrap().phys.get(2));
+    }
+}
@@ -0,0 +1,84 @@
+use serde_json::{Map, Value};
+
+use super::Table;
+use crate::error::ReturnError;
+
+
+/// is the column that EVDS gives as `{"$numberLong": "..."}` object.
+const UNIXTIME_COLUMN: &str = "UNIXTIME";
+
+
+/// converts a JSON value of a response item to a cell.
+fn to_cell(value: &Value) -> Option<String> {
+    match value {
+        Value::Null => None,
+        Value::String(text) => Some(text.clone()),
+        Value::Object(object) => match object.get("$numberLong") {
+            Some(Value::String(number)) => Some(number.clone()),
+            _ => Some(value.to_string()),
+        },
+        _ => Some(value.to_string()),
+    }
+}
+
+/// parses `items` of a JSON response or the items of a JSON list such as the response of series list.
+///
+/// # Error
+///
+/// This function returns an error if the response is neither a JSON object containing `items` list nor a JSON list.
+pub(crate) fn parse(response: &str) -> Result<Table, ReturnError> {
+    let document: Value = serde_json::from_str(response).map_err(|_| ReturnError::UnableToParseResponse)?;
+
+    let items = match &document {
+        Value::Array(items) => items,
+        _ => match document.get("items") {
+            Some(Value::Array(items)) => items,
+            _ => return Err(ReturnError::UnableToParseResponse),
+        },
+    };
+
+    let mut columns: Vec<String> = Vec::new();
+
+    for item in items {
+        let object = item.as_object().ok_or(ReturnError::UnableToParseResponse)?;
+
+        for key in object.keys() {
+            if !columns.contains(key) { columns.push(key.clone()); }
+        }
+    }
+
+    let rows = items.iter().map(|item| {
+        columns.iter().map(|column| item.get(column).and_then(to_cell)).collect()
+    }).collect();
+
+    Ok(Table { columns, rows })
+}
+
+/// renders the table as `{"totalCount": .., "items": [..]}`.
+pub(crate) fn render(table: &Table) -> String {
+    let items: Vec<Value> = table.rows.iter().map(|row| {
+        let mut item = Map::new();
+
+        for (column, cell) in table.columns.iter().zip(row.iter()) {
+            let value = match cell {
+                None => Value::Null,
+                Some(text) if column == UNIXTIME_COLUMN => {
+                    let mut number = Map::new();
+                    number.insert("$numberLong".to_string(), Value::String(text.clone()));
+                    Value::Object(number)
+                },
+                Some(text) => Value::String(text.clone()),
+            };
+
+            item.insert(column.clone(), value);
+        }
+
+        Value::Object(item)
+    }).collect();
+
+    let mut document = Map::new();
+    document.insert("totalCount".to_string(), Value::from(items.len()));
+    document.insert("items".to_string(), Value::Array(items));
+
+    Value::Object(document).to_string()
+}
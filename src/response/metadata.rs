@@ -0,0 +1,106 @@
+use super::Table;
+use crate::common::ReturnFormat;
+use crate::error::ReturnError;
+use crate::evds_currency::frequency_formulas::DataFrequency;
+
+
+/// is the typed form of a series in the response of [`get_series_list`](fn@crate::evds_basic::get_series_list).
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesMetadata {
+    /// series code such as "TP.DK.USD.A".
+    pub code: String,
+    pub data_group: Option<String>,
+    /// name in Turkish.
+    pub name: Option<String>,
+    /// name in English.
+    pub name_eng: Option<String>,
+    /// unit of the values when the series list gives it.
+    pub unit: Option<String>,
+    /// frequency as given by EVDS. [`data_frequency`](fn@SeriesMetadata::data_frequency) gives its typed form.
+    pub frequency: Option<String>,
+    /// first date of the series.
+    pub start_date: Option<String>,
+    /// last date of the series.
+    pub end_date: Option<String>,
+}
+
+impl SeriesMetadata {
+    /// parses the response of series list received in given return format.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the response cannot be parsed or a series does not have a code.
+    pub fn parse_list(response: &str, return_format: &ReturnFormat) -> Result<Vec<SeriesMetadata>, ReturnError> {
+        let table = Table::parse(response, return_format)?;
+
+        let code_index = match table.column_index("SERIE_CODE") {
+            Some(index) => index,
+            None if table.rows.is_empty() => return Ok(Vec::new()),
+            None => return Err(ReturnError::UnableToParseResponse),
+        };
+
+        let index_of = |column: &str| table.column_index(column);
+
+        let data_group_index = index_of("DATAGROUP_CODE");
+        let name_index = index_of("SERIE_NAME");
+        let name_eng_index = index_of("SERIE_NAME_ENG");
+        let unit_index = index_of("UNIT");
+        let frequency_index = index_of("FREQUENCY_STR");
+        let start_date_index = index_of("START_DATE");
+        let end_date_index = index_of("END_DATE");
+
+        table.rows.iter().map(|row| {
+            let cell = |index: Option<usize>| index.and_then(|index| row[index].clone());
+
+            Ok(SeriesMetadata {
+                code: row[code_index].clone().ok_or(ReturnError::UnableToParseResponse)?,
+                data_group: cell(data_group_index),
+                name: cell(name_index),
+                name_eng: cell(name_eng_index),
+                unit: cell(unit_index),
+                frequency: cell(frequency_index),
+                start_date: cell(start_date_index),
+                end_date: cell(end_date_index),
+            })
+        }).collect()
+    }
+
+    /// gives the frequency of the series if it is a known frequency.
+    pub fn data_frequency(&self) -> Option<DataFrequency> {
+        self.frequency.as_deref().and_then(DataFrequency::from_metadata)
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_series_list() {
+        let response = r#"[
+            {"SERIE_CODE":"TP.DK.USD.A","DATAGROUP_CODE":"bie_dkdovytl","SERIE_NAME":"(USD) ABD Doları (Döviz Alış)",
+            "SERIE_NAME_ENG":"(USD) US Dollar (Buying)","FREQUENCY_STR":"İŞ GÜNÜ","START_DATE":"02-01-1950"},
+            {"SERIE_CODE":"TP.FG.J0","FREQUENCY_STR":"AYLIK","UNIT":"%"}
+        ]"#;
+
+        let series_list = SeriesMetadata::parse_list(response, &ReturnFormat::Json).unwrap();
+
+        assert_eq!(2, series_list.len());
+        assert_eq!("TP.DK.USD.A", series_list[0].code);
+        assert_eq!(Some("(USD) US Dollar (Buying)".to_string()), series_list[0].name_eng);
+        assert_eq!(Some(DataFrequency::Business), series_list[0].data_frequency());
+        assert_eq!(Some(DataFrequency::Monthly), series_list[1].data_frequency());
+        assert_eq!(Some(DataFrequency::Quarterly), DataFrequency::from_metadata("3 AYLIK"));
+        assert_eq!(Some(DataFrequency::TwiceMonthly), DataFrequency::from_metadata(" AYDA 2 KEZ "));
+        assert_eq!(Some(DataFrequency::Annual), DataFrequency::from_metadata("8"));
+        assert_eq!(None, DataFrequency::from_metadata("Üç Aylık"));
+        assert_eq!(None, DataFrequency::from_metadata("WEEKLY"));
+        assert_eq!(None, DataFrequency::from_metadata("AYLIK ORTALAMA"));
+        assert_eq!(None, DataFrequency::from_metadata("unknown"));
+        assert_eq!(None, series_list[1].end_date);
+        assert_eq!(Some("%".to_string()), series_list[1].unit);
+
+        assert_eq!(Ok(Vec::new()), SeriesMetadata::parse_list("[]", &ReturnFormat::Json));
+    }
+}
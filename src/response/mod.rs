@@ -0,0 +1,254 @@
+/// parses and renders JSON responses.
+mod json;
+/// parses and renders CSV responses.
+mod csv;
+/// parses and renders XML responses.
+mod xml;
+/// contains the typed form of data series responses.
+mod observations;
+/// contains the typed form of series list responses.
+mod metadata;
+/// converts observations to Polars data frames.
+#[cfg(feature = "dataframe")]
+mod frame;
+/// converts observations to Arrow record batches.
+#[cfg(feature = "arrow")]
+mod batch;
+
+use std::collections::HashMap;
+
+use crate::common::ReturnFormat;
+use crate::date::calendar::Day;
+use crate::error::ReturnError;
+
+pub use self::observations::{Observation, Observations};
+pub use self::metadata::SeriesMetadata;
+pub(crate) use self::observations::{parse_value, series_name};
+
+
+/// is the name of the date column in each response format of EVDS.
+pub(crate) const DATE_COLUMN: &str = "Tarih";
+
+
+/// is the format independent representation of a data series response.
+///
+/// Each row has a cell for each column and empty values are kept as `None`.
+#[derive(Debug, PartialEq)]
+pub(crate) struct Table {
+    pub(crate) columns: Vec<String>,
+    pub(crate) rows: Vec<Vec<Option<String>>>,
+}
+
+impl Table {
+    /// parses a response received in given return format.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the response cannot be parsed.
+    pub(crate) fn parse(response: &str, return_format: &ReturnFormat) -> Result<Table, ReturnError> {
+        match return_format {
+            ReturnFormat::Json => json::parse(response),
+            ReturnFormat::Csv => csv::parse(response),
+            ReturnFormat::Xml => xml::parse(response),
+        }
+    }
+
+    /// renders the table in given return format like a response of EVDS.
+    pub(crate) fn render(&self, return_format: &ReturnFormat) -> String {
+        match return_format {
+            ReturnFormat::Json => json::render(self),
+            ReturnFormat::Csv => csv::render(self),
+            ReturnFormat::Xml => xml::render(self),
+        }
+    }
+
+    /// gives index of the given column.
+    pub(crate) fn column_index(&self, column: &str) -> Option<usize> {
+        self.columns.iter().position(|element| element == column)
+    }
+
+    /// merges tables by their date columns.
+    ///
+    /// Columns are united in order of appearance and cells that a table does not have are left empty. Rows are ordered
+    /// by date when each date is a date of EVDS, otherwise by first appearance of their dates.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if a table does not have the date column or two tables give different values of
+    /// the same column for the same date.
+    pub(crate) fn merge(tables: Vec<Table>) -> Result<Table, ReturnError> {
+        let mut merged = Table { columns: vec![DATE_COLUMN.to_string()], rows: Vec::new() };
+        let mut row_indices: HashMap<String, usize> = HashMap::new();
+
+        for table in tables {
+            let date_index = table.column_index(DATE_COLUMN).ok_or(ReturnError::UnableToMergeResponses)?;
+
+            let column_indices: Vec<usize> = table.columns.iter().map(|column| {
+                match merged.column_index(column) {
+                    Some(index) => index,
+                    None => {
+                        merged.columns.push(column.clone());
+                        merged.rows.iter_mut().for_each(|row| row.push(None));
+                        merged.columns.len() - 1
+                    },
+                }
+            }).collect();
+
+            for row in table.rows {
+                let date = row.get(date_index).cloned().flatten().unwrap_or_default();
+
+                let row_index = *row_indices.entry(date.clone()).or_insert_with(|| {
+                    merged.rows.push(vec![None; merged.columns.len()]);
+                    merged.rows.len() - 1
+                });
+
+                for (cell, column_index) in row.into_iter().zip(column_indices.iter()) {
+                    let value = match cell {
+                        Some(value) => value,
+                        None => continue,
+                    };
+
+                    match &merged.rows[row_index][*column_index] {
+                        Some(existing) if !existing.trim().is_empty() => {
+                            if !value.trim().is_empty() && existing.trim() != value.trim() {
+                                return Err(ReturnError::ConflictingObservations(format!(
+                                    "{} has different values on {}",
+                                    merged.columns[*column_index], date
+                                )));
+                            }
+                        },
+                        _ => merged.rows[row_index][*column_index] = Some(value),
+                    }
+                }
+            }
+        }
+
+        let days: Option<Vec<Day>> = merged.rows.iter()
+            .map(|row| row[0].as_deref().and_then(Day::parse_period))
+            .collect();
+
+        if let Some(days) = days {
+            let mut rows: Vec<(Day, Vec<Option<String>>)> = days.into_iter().zip(merged.rows).collect();
+            rows.sort_by_key(|(day, _)| *day);
+            merged.rows = rows.into_iter().map(|(_, row)| row).collect();
+        }
+
+        Ok(merged)
+    }
+}
+
+
+/// merges responses of the same date range requested for different series into a single response.
+///
+/// # Error
+///
+/// This function returns an error if one of the responses cannot be parsed or does not have dates.
+pub(crate) fn merge_responses(responses: &[String], return_format: &ReturnFormat) -> Result<String, ReturnError> {
+    let tables = responses.iter()
+        .map(|response| Table::parse(response, return_format))
+        .collect::<Result<Vec<Table>, ReturnError>>()?;
+
+    Ok(Table::merge(tables)?.render(return_format))
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(columns: &[&str], rows: &[&[Option<&str>]]) -> Table {
+        Table {
+            columns: columns.iter().map(|column| column.to_string()).collect(),
+            rows: rows.iter()
+                .map(|row| row.iter().map(|cell| cell.map(|cell| cell.to_string())).collect())
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn should_merge_tables_by_date() {
+        let former = table(
+            &["Tarih", "TP_DK_USD_A"],
+            &[&[Some("01-01-2020"), Some("5.9")], &[Some("02-01-2020"), Some("5.95")]]
+        );
+        let latter = table(
+            &["Tarih", "TP_DK_EUR_A"],
+            &[&[Some("02-01-2020"), Some("6.6")], &[Some("03-01-2020"), None]]
+        );
+
+        let expected = table(
+            &["Tarih", "TP_DK_USD_A", "TP_DK_EUR_A"],
+            &[
+                &[Some("01-01-2020"), Some("5.9"), None],
+                &[Some("02-01-2020"), Some("5.95"), Some("6.6")],
+                &[Some("03-01-2020"), None, None],
+            ]
+        );
+
+        assert_eq!(expected, Table::merge(vec![former, latter]).unwrap());
+    }
+
+    #[test]
+    fn should_order_rows_by_date_and_reject_conflicts() {
+        let former = table(&["Tarih", "TP_DK_USD_A"], &[&[Some("03-01-2020"), Some("5.97")]]);
+        let latter = table(
+            &["Tarih", "TP_DK_USD_A"],
+            &[&[Some("02-01-2020"), Some("5.95")], &[Some("03-01-2020"), Some("")]]
+        );
+
+        let expected = table(
+            &["Tarih", "TP_DK_USD_A"],
+            &[&[Some("02-01-2020"), Some("5.95")], &[Some("03-01-2020"), Some("5.97")]]
+        );
+
+        assert_eq!(expected, Table::merge(vec![former, latter]).unwrap());
+
+        let former = table(&["Tarih", "TP_DK_USD_A"], &[&[Some("2020-1"), Some("5.9")]]);
+        let latter = table(&["Tarih", "TP_DK_USD_A"], &[&[Some("2020-1"), Some("6.1")]]);
+
+        assert_eq!(
+            Err(ReturnError::ConflictingObservations("TP_DK_USD_A has different values on 2020-1".to_string())),
+            Table::merge(vec![former, latter])
+        );
+    }
+
+    #[test]
+    fn should_reject_tables_without_dates() {
+        let table = table(&["TP_DK_USD_A"], &[&[Some("5.9")]]);
+
+        assert_eq!(Err(ReturnError::UnableToMergeResponses), Table::merge(vec![table]));
+    }
+
+    #[test]
+    fn should_merge_each_format() {
+        let json_responses = vec![
+            r#"{"totalCount":1,"items":[{"Tarih":"01-01-2020","TP_DK_USD_A":"5.9"}]}"#.to_string(),
+            r#"{"totalCount":1,"items":[{"Tarih":"01-01-2020","TP_DK_EUR_A":"6.6"}]}"#.to_string(),
+        ];
+        assert_eq!(
+            r#"{"totalCount":1,"items":[{"Tarih":"01-01-2020","TP_DK_USD_A":"5.9","TP_DK_EUR_A":"6.6"}]}"#,
+            merge_responses(&json_responses, &ReturnFormat::Json).unwrap()
+        );
+
+        let csv_responses = vec![
+            "Tarih,TP_DK_USD_A\n01-01-2020,5.9\n".to_string(),
+            "Tarih,TP_DK_EUR_A\n01-01-2020,6.6\n".to_string(),
+        ];
+        assert_eq!(
+            "Tarih,TP_DK_USD_A,TP_DK_EUR_A\n01-01-2020,5.9,6.6\n",
+            merge_responses(&csv_responses, &ReturnFormat::Csv).unwrap()
+        );
+
+        let xml_responses = vec![
+            "<document><totalCount>1</totalCount><items><Tarih>01-01-2020</Tarih>\
+            <TP_DK_USD_A>5.9</TP_DK_USD_A></items></document>".to_string(),
+            "<document><totalCount>1</totalCount><items><Tarih>01-01-2020</Tarih>\
+            <TP_DK_EUR_A>6.6</TP_DK_EUR_A></items></document>".to_string(),
+        ];
+        assert_eq!(
+            "<document><totalCount>1</totalCount><items><Tarih>01-01-2020</Tarih><TP_DK_USD_A>5.9</TP_DK_USD_A>\
+            <TP_DK_EUR_A>6.6</TP_DK_EUR_A></items></document>",
+            merge_responses(&xml_responses, &ReturnFormat::Xml).unwrap()
+        );
+    }
+}
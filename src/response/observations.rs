@@ -0,0 +1,297 @@
+#[cfg(feature = "decimal")]
+use rust_decimal::Decimal;
+
+use super::{Table, DATE_COLUMN};
+use crate::common::ReturnFormat;
+use crate::error::ReturnError;
+
+
+/// are the columns of EVDS responses that are neither dates nor series.
+const NON_SERIES_COLUMNS: [&str; 2] = ["UNIXTIME", "YEARWEEK"];
+
+
+/// is a row of [`Observations`](struct@Observations) holding a value for each series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observation<T = f64> {
+    /// date as given by EVDS such as "13-12-2011" for daily, "2011-12" for monthly or "2011-Q4" for quarterly series.
+    pub date: String,
+    /// values in the order of series. Missing values are `None`.
+    pub values: Vec<Option<T>>,
+}
+
+
+/// is the typed form of a data series response that is independent of the return format.
+///
+/// Series are named as EVDS names them in responses, where dots of the series codes are replaced with underscores,
+/// e.g. "TP_DK_USD_A" for "TP.DK.USD.A".
+///
+/// Values are `f64` by default. With the `decimal` feature, [`parse_decimal`](fn@Observations::parse_decimal) gives
+/// exact `rust_decimal::Decimal` values instead.
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+///     use tcmb_evds::common::ReturnFormat;
+///     use tcmb_evds::response::Observations;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+///     let response = r#"{"totalCount":1,"items":[{"Tarih":"13-12-2011","TP_DK_USD_A":"1.8365"}]}"#;
+///
+///     let observations = Observations::parse(response, &ReturnFormat::Json)?;
+///
+///     assert_eq!(Some(1.8365), observations.value("13-12-2011", "TP.DK.USD.A"));
+/// #   Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct Observations<T = f64> {
+    /// series names in the order of values of each observation.
+    pub series: Vec<String>,
+    /// observations in the order of the response.
+    pub rows: Vec<Observation<T>>,
+}
+
+impl Observations {
+    /// parses a data series response received in given return format.
+    ///
+    /// Values written with a decimal comma such as "1,8365" are also accepted.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the response cannot be parsed, does not have dates or contains a value that is
+    /// not a number.
+    pub fn parse(response: &str, return_format: &ReturnFormat) -> Result<Observations, ReturnError> {
+        Observations::parse_series(response, return_format, "")
+    }
+
+    /// parses a response of the given data series such as "TP.DK.USD.A-TP.DK.EUR.A" received in given return format.
+    ///
+    /// An empty response such as `{"items":[]}`, which EVDS gives when there is no observation in the date range, is
+    /// parsed as the given series without observations.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`parse`](fn@Observations::parse).
+    pub fn parse_series(
+        response: &str,
+        return_format: &ReturnFormat,
+        data_series: &str,
+    ) -> Result<Observations, ReturnError> {
+        Observations::from_table(Table::parse(response, return_format)?, data_series)
+    }
+
+    pub(crate) fn from_table(table: Table, data_series: &str) -> Result<Observations, ReturnError> {
+        Observations::from_table_with(table, data_series, parse_value)
+    }
+}
+
+#[cfg(feature = "decimal")]
+impl Observations<Decimal> {
+    /// parses a data series response received in given return format into exact decimal values.
+    ///
+    /// Values are not rounded through `f64`. Both decimal points and decimal commas such as "1.8365" and "1,8365" are
+    /// accepted.
+    ///
+    /// This function is available with the `decimal` feature.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the response cannot be parsed, does not have dates or contains a value that is
+    /// not a number or cannot be represented exactly.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::error::Error;
+    ///     use rust_decimal::Decimal;
+    ///
+    ///     use tcmb_evds::common::ReturnFormat;
+    ///     use tcmb_evds::response::Observations;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///     let response = "Tarih,TP_DK_USD_A\n13-12-2011,\"1,8365\"\n";
+    ///
+    ///     let observations = Observations::parse_decimal(response, &ReturnFormat::Csv)?;
+    ///
+    ///     assert_eq!(Some(Decimal::new(18365, 4)), observations.value("13-12-2011", "TP.DK.USD.A"));
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn parse_decimal(response: &str, return_format: &ReturnFormat) -> Result<Observations<Decimal>, ReturnError> {
+        Observations::parse_decimal_series(response, return_format, "")
+    }
+
+    /// parses a response of the given data series into exact decimal values.
+    ///
+    /// An empty response is parsed as the given series without observations as in
+    /// [`parse_series`](fn@Observations::parse_series).
+    ///
+    /// This function is available with the `decimal` feature.
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`parse_decimal`](fn@Observations::parse_decimal).
+    pub fn parse_decimal_series(
+        response: &str,
+        return_format: &ReturnFormat,
+        data_series: &str,
+    ) -> Result<Observations<Decimal>, ReturnError> {
+        Observations::from_table_with(Table::parse(response, return_format)?, data_series, parse_decimal_value)
+    }
+}
+
+impl<T: Copy> Observations<T> {
+    /// creates observations from a table by parsing its values with the given function.
+    ///
+    /// A table without columns and rows is an empty response, and gives the data series without observations.
+    fn from_table_with(
+        table: Table,
+        data_series: &str,
+        parse: fn(&str) -> Result<T, ReturnError>,
+    ) -> Result<Observations<T>, ReturnError> {
+        if table.columns.is_empty() && table.rows.is_empty() {
+            let series = data_series.split('-')
+                .filter(|series| !series.trim().is_empty())
+                .map(series_name)
+                .collect();
+
+            return Ok(Observations { series, rows: Vec::new() });
+        }
+
+        let date_index = table.column_index(DATE_COLUMN).ok_or(ReturnError::UnableToParseResponse)?;
+
+        let series_indices: Vec<usize> = (0..table.columns.len())
+            .filter(|index| *index != date_index && !NON_SERIES_COLUMNS.contains(&table.columns[*index].as_str()))
+            .collect();
+
+        let series = series_indices.iter().map(|index| table.columns[*index].clone()).collect();
+
+        let rows = table.rows.into_iter().map(|mut row| {
+            let date = row[date_index].take().ok_or(ReturnError::UnableToParseResponse)?;
+
+            let values = series_indices.iter()
+                .map(|index| row[*index].as_deref().map(parse).transpose())
+                .collect::<Result<Vec<Option<T>>, ReturnError>>()?;
+
+            Ok(Observation { date, values })
+        }).collect::<Result<Vec<Observation<T>>, ReturnError>>()?;
+
+        Ok(Observations { series, rows })
+    }
+
+    /// gives index of the series given as a code such as "TP.DK.USD.A" or as named in responses such as "TP_DK_USD_A".
+    pub fn series_index(&self, series: &str) -> Option<usize> {
+        let name = series_name(series);
+
+        self.series.iter().position(|element| *element == name)
+    }
+
+    /// gives the value of the series at the given date if there is.
+    pub fn value(&self, date: &str, series: &str) -> Option<T> {
+        let index = self.series_index(series)?;
+
+        self.rows.iter().find(|row| row.date == date).and_then(|row| row.values[index])
+    }
+}
+
+
+/// converts a series code such as "TP.DK.USD.A" to its name in responses such as "TP_DK_USD_A".
+pub(crate) fn series_name(series: &str) -> String {
+    series.trim().replace('.', "_")
+}
+
+/// parses a value written with a decimal point or a decimal comma.
+///
+/// # Error
+///
+/// This function returns an error if the value is not a number.
+pub(crate) fn parse_value(value: &str) -> Result<f64, ReturnError> {
+    value.trim().replace(',', ".").parse::<f64>().map_err(|_| ReturnError::UnableToParseResponse)
+}
+
+/// parses a value written with a decimal point or a decimal comma without rounding.
+///
+/// # Error
+///
+/// This function returns an error if the value is not a number or has more digits than a decimal can hold.
+#[cfg(feature = "decimal")]
+pub(crate) fn parse_decimal_value(value: &str) -> Result<Decimal, ReturnError> {
+    Decimal::from_str_exact(&value.trim().replace(',', ".")).map_err(|_| ReturnError::UnableToParseResponse)
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn should_parse_each_format_alike() {
+        let json = r#"{"totalCount":2,"items":[
+            {"Tarih":"01-01-2020","TP_DK_USD_A":"5.9","TP_DK_EUR_A":null,"UNIXTIME":{"$numberLong":"1577826000"}},
+            {"Tarih":"02-01-2020","TP_DK_USD_A":"5,95","TP_DK_EUR_A":"6.6","UNIXTIME":{"$numberLong":"1577912400"}}
+        ]}"#;
+        let csv = "Tarih,TP_DK_USD_A,TP_DK_EUR_A\n01-01-2020,5.9,\n02-01-2020,\"5,95\",6.6\n";
+        let xml = "<document><totalCount>2</totalCount>\
+            <items><Tarih>01-01-2020</Tarih><TP_DK_USD_A>5.9</TP_DK_USD_A><TP_DK_EUR_A/></items>\
+            <items><Tarih>02-01-2020</Tarih><TP_DK_USD_A>5,95</TP_DK_USD_A><TP_DK_EUR_A>6.6</TP_DK_EUR_A></items>\
+            </document>";
+
+        let expected = Observations {
+            series: vec!["TP_DK_USD_A".to_string(), "TP_DK_EUR_A".to_string()],
+            rows: vec![
+                Observation { date: "01-01-2020".to_string(), values: vec![Some(5.9), None] },
+                Observation { date: "02-01-2020".to_string(), values: vec![Some(5.95), Some(6.6)] },
+            ],
+        };
+
+        assert_eq!(expected, Observations::parse(json, &ReturnFormat::Json).unwrap());
+        assert_eq!(expected, Observations::parse(csv, &ReturnFormat::Csv).unwrap());
+        assert_eq!(expected, Observations::parse(xml, &ReturnFormat::Xml).unwrap());
+
+        assert_eq!(Some(6.6), expected.value("02-01-2020", "TP.DK.EUR.A"));
+        assert_eq!(None, expected.value("01-01-2020", "TP_DK_EUR_A"));
+    }
+
+    #[test]
+    fn should_reject_values_that_are_not_numbers() {
+        let csv = "Tarih,TP_DK_USD_A\n01-01-2020,five\n";
+
+        assert_eq!(Err(ReturnError::UnableToParseResponse), Observations::parse(csv, &ReturnFormat::Csv));
+    }
+
+    #[test]
+    fn should_parse_empty_response_as_series_without_observations() {
+        let json = r#"{"totalCount":0,"items":[]}"#;
+
+        let observations = Observations::parse_series(json, &ReturnFormat::Json, "TP.DK.USD.A-TP.DK.EUR.A").unwrap();
+
+        assert_eq!(vec!["TP_DK_USD_A".to_string(), "TP_DK_EUR_A".to_string()], observations.series);
+        assert!(observations.rows.is_empty());
+        assert_eq!(Some(1), observations.series_index("TP.DK.EUR.A"));
+
+        let without_series = Observations { series: Vec::new(), rows: Vec::new() };
+
+        assert_eq!(Ok(without_series), Observations::parse(json, &ReturnFormat::Json));
+        assert_eq!(
+            Err(ReturnError::UnableToParseResponse),
+            Observations::parse_series("TP_DK_USD_A\n\"1,8365\"\n", &ReturnFormat::Csv, "TP.DK.USD.A")
+        );
+    }
+
+    #[cfg(feature = "decimal")]
+    #[test]
+    fn should_parse_exact_decimals() {
+        let xml = "<document><items><Tarih>13-12-2011</Tarih><TP_DK_USD_A>1,83650000000000000001</TP_DK_USD_A>\
+            <TP_DK_EUR_A>2.4012</TP_DK_EUR_A></items></document>";
+
+        let observations = Observations::parse_decimal(xml, &ReturnFormat::Xml).unwrap();
+
+        assert_eq!(Some(Decimal::new(24012, 4)), observations.value("13-12-2011", "TP.DK.EUR.A"));
+        assert_eq!(
+            Some("1.83650000000000000001".parse::<Decimal>().unwrap()),
+            observations.value("13-12-2011", "TP_DK_USD_A")
+        );
+        assert_eq!(Err(ReturnError::UnableToParseResponse), parse_decimal_value("1.8365e"));
+    }
+}
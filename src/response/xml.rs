@@ -0,0 +1,103 @@
+use super::Table;
+use crate::error::ReturnError;
+
+
+/// gives the elements of an `<items>` block as tag and text pairs in order.
+///
+/// Self closing elements such as `<TP_DK_USD_A/>` are given without text.
+fn parse_elements(block: &str) -> Result<Vec<(String, Option<String>)>, ReturnError> {
+    let mut elements = Vec::new();
+    let mut rest = block.trim();
+
+    while !rest.is_empty() {
+        if !rest.starts_with('<') { return Err(ReturnError::UnableToParseResponse) }
+
+        let tag_end = rest.find('>').ok_or(ReturnError::UnableToParseResponse)?;
+        let tag = &rest[1..tag_end];
+
+        if let Some(tag) = tag.strip_suffix('/') {
+            elements.push((tag.trim().to_string(), None));
+            rest = rest[tag_end + 1..].trim_start();
+            continue;
+        }
+
+        let closing_tag = format!("</{}>", tag);
+        let content_end = rest.find(&closing_tag).ok_or(ReturnError::UnableToParseResponse)?;
+        let text = unescape(&rest[tag_end + 1..content_end]);
+
+        elements.push((tag.to_string(), if text.is_empty() { None } else { Some(text) }));
+        rest = rest[content_end + closing_tag.len()..].trim_start();
+    }
+
+    Ok(elements)
+}
+
+fn unescape(text: &str) -> String {
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&apos;", "'")
+        .replace("&amp;", "&")
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// parses `<items>` blocks of an XML response.
+///
+/// # Error
+///
+/// This function returns an error if an `<items>` block is not well formed.
+pub(crate) fn parse(response: &str) -> Result<Table, ReturnError> {
+    let mut columns: Vec<String> = Vec::new();
+    let mut items = Vec::new();
+    let mut rest = response;
+
+    while let Some(start) = rest.find("<items>") {
+        let end = rest[start..].find("</items>").ok_or(ReturnError::UnableToParseResponse)? + start;
+
+        let elements = parse_elements(&rest[start + "<items>".len()..end])?;
+
+        for (tag, _) in &elements {
+            if !columns.contains(tag) { columns.push(tag.clone()); }
+        }
+
+        items.push(elements);
+        rest = &rest[end + "</items>".len()..];
+    }
+
+    if items.is_empty() && !response.contains("<document") { return Err(ReturnError::UnableToParseResponse) }
+
+    let rows = items.into_iter().map(|elements| {
+        columns.iter().map(|column| {
+            elements.iter().find(|(tag, _)| tag == column).and_then(|(_, text)| text.clone())
+        }).collect()
+    }).collect();
+
+    Ok(Table { columns, rows })
+}
+
+/// renders the table as `<document>` with `<totalCount>` and an `<items>` block for each row.
+pub(crate) fn render(table: &Table) -> String {
+    let mut output = format!("<document><totalCount>{}</totalCount>", table.rows.len());
+
+    for row in &table.rows {
+        output.push_str("<items>");
+
+        for (column, cell) in table.columns.iter().zip(row.iter()) {
+            match cell {
+                Some(text) => output.push_str(&format!("<{}>{}</{}>", column, escape(text), column)),
+                None => output.push_str(&format!("<{}/>", column)),
+            }
+        }
+
+        output.push_str("</items>");
+    }
+
+    output.push_str("</document>");
+
+    output
+}
@@ -0,0 +1,160 @@
+use crate::common::Evds;
+use crate::error::ReturnError;
+use crate::response;
+
+
+/// is the separator of the series in a url.
+const SERIES_SEPARATOR: char = '-';
+
+
+/// groups the series so that the url of each group respects the length and series count limits.
+///
+/// `compose_url` generates the whole url of a given series group. A series exceeding the length limit alone is given
+/// as a single group, since it cannot be divided.
+pub(crate) fn split_series<F>(
+    series: &str,
+    compose_url: &F,
+    max_url_length: usize,
+    max_series_per_request: Option<usize>,
+) -> Vec<String>
+where
+    F: Fn(&str) -> String,
+{
+    let max_series_per_request = max_series_per_request.unwrap_or(usize::MAX).max(1);
+
+    let mut groups: Vec<String> = Vec::new();
+    let mut group = String::new();
+    let mut group_size = 0;
+
+    for element in series.split(SERIES_SEPARATOR).filter(|element| !element.is_empty()) {
+        if group_size > 0 {
+            let extended_group = format!("{}{}{}", group, SERIES_SEPARATOR, element);
+
+            if group_size < max_series_per_request && compose_url(&extended_group).len() <= max_url_length {
+                group = extended_group;
+                group_size += 1;
+                continue;
+            }
+
+            groups.push(std::mem::take(&mut group));
+        }
+
+        group = element.to_string();
+        group_size = 1;
+    }
+
+    if group_size > 0 { groups.push(group); }
+
+    groups
+}
+
+/// generates the urls of given series in as many requests as the url limits of the client configuration require.
+///
+/// When the series fit into a single url, the url of all series is given alone.
+pub(crate) fn urls_in_parts<F>(series: &str, compose_url: F, evds: &Evds) -> Vec<String>
+where
+    F: Fn(&str) -> String,
+{
+    let client_config = &evds.get_transport().client_config;
+
+    let groups = split_series(
+        series,
+        &compose_url,
+        client_config.get_max_url_length(),
+        client_config.get_max_series_per_request()
+    );
+
+    if groups.len() < 2 {
+        return vec![compose_url(series)];
+    }
+
+    groups.iter().map(|group| compose_url(group)).collect()
+}
+
+/// requests given urls of the parts of series generated by [`urls_in_parts`](fn@urls_in_parts).
+///
+/// When there is a single url, the response is returned as it is. Otherwise, responses of the parts are merged by
+/// date into a single response in the return format of the given [`Evds`](struct@crate::common::Evds).
+///
+/// # Error
+///
+/// This function returns the first error of the part requests or an error if the responses cannot be merged.
+pub(crate) fn request_urls<R>(urls: &[String], evds: &Evds, request: R) -> Result<String, ReturnError>
+where
+    R: Fn(&str) -> Result<String, ReturnError>,
+{
+    if let [url] = urls {
+        return request(url);
+    }
+
+    let responses = urls.iter()
+        .map(|url| request(url))
+        .collect::<Result<Vec<String>, ReturnError>>()?;
+
+    response::merge_responses(&responses, evds.get_return_format())
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::common::{ApiKey, ClientConfig, ReturnFormat};
+
+    fn compose_url(series: &str) -> String {
+        format!("https://evds2.tcmb.gov.tr/service/evds/series={}&type=csv", series)
+    }
+
+    #[test]
+    fn should_split_by_url_length() {
+        let series = "TP.DK.USD.A-TP.DK.EUR.A-TP.DK.GBP.A";
+        let limit = compose_url("TP.DK.USD.A-TP.DK.EUR.A").len();
+
+        assert_eq!(
+            vec!["TP.DK.USD.A-TP.DK.EUR.A".to_string(), "TP.DK.GBP.A".to_string()],
+            split_series(series, &compose_url, limit, None)
+        );
+        assert_eq!(vec![series.to_string()], split_series(series, &compose_url, 2000, None));
+        assert_eq!(3, split_series(series, &compose_url, 10, None).len());
+    }
+
+    #[test]
+    fn should_split_by_series_count() {
+        let series = "TP.DK.USD.A-TP.DK.EUR.A-TP.DK.GBP.A-TP.DK.JPY.A-TP.DK.CHF.A";
+
+        assert_eq!(
+            vec!["TP.DK.USD.A-TP.DK.EUR.A", "TP.DK.GBP.A-TP.DK.JPY.A", "TP.DK.CHF.A"],
+            split_series(series, &compose_url, 2000, Some(2))
+        );
+    }
+
+    #[test]
+    fn should_request_parts_and_merge() {
+        let mut evds = Evds::from(ApiKey::unchecked("abc"), ReturnFormat::Csv);
+
+        let mut client_config = ClientConfig::new();
+        client_config.change_max_series_per_request(Some(1));
+        evds.change_client_config(client_config);
+
+        let requested_urls = RefCell::new(Vec::new());
+
+        let urls = urls_in_parts("TP.DK.USD.A-TP.DK.EUR.A", compose_url, &evds);
+
+        let response = request_urls(&urls, &evds, |url| {
+            requested_urls.borrow_mut().push(url.to_string());
+
+            if url.contains("USD") {
+                return Ok("Tarih,TP_DK_USD_A\n02-01-2020,5.9\n03-01-2020,5.95\n".to_string());
+            }
+
+            Ok("Tarih,TP_DK_EUR_A\n02-01-2020,6.6\n03-01-2020,6.65\n".to_string())
+        });
+
+        assert_eq!(
+            Ok("Tarih,TP_DK_USD_A,TP_DK_EUR_A\n02-01-2020,5.9,6.6\n03-01-2020,5.95,6.65\n".to_string()),
+            response
+        );
+        assert_eq!(vec![compose_url("TP.DK.USD.A"), compose_url("TP.DK.EUR.A")], requested_urls.into_inner());
+    }
+}
@@ -0,0 +1,584 @@
+/// keeps vintages of revised observations.
+mod vintage;
+
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use rusqlite::{params, Connection, OptionalExtension};
+
+use crate::common::Evds;
+use crate::completeness::{self, BusinessCalendar, CompletenessReport};
+use crate::date::calendar::{self, Day};
+use crate::date::{DatePreference, DateRange};
+use crate::error::ReturnError;
+use crate::evds_basic;
+use crate::evds_currency::frequency_formulas::DataFrequency;
+use crate::response::{self, Observation, Observations, SeriesMetadata};
+
+pub use self::vintage::Revision;
+
+
+const SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS observations (
+        series TEXT NOT NULL,
+        date TEXT NOT NULL,
+        value REAL,
+        PRIMARY KEY (series, date)
+    );
+    CREATE TABLE IF NOT EXISTS sync_state (
+        series TEXT PRIMARY KEY,
+        last_date TEXT NOT NULL,
+        synced_at INTEGER NOT NULL
+    );
+    CREATE TABLE IF NOT EXISTS vintages (
+        series TEXT NOT NULL,
+        date TEXT NOT NULL,
+        value REAL,
+        retrieved_at INTEGER NOT NULL,
+        PRIMARY KEY (series, date, retrieved_at)
+    );
+";
+
+
+/// contains the result of synchronising a series.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SeriesSync {
+    /// series code as given to [`sync`](fn@Store::sync).
+    pub series: String,
+    /// first requested date in "day-month-year" format.
+    pub start_date: String,
+    /// number of inserted or updated observations.
+    pub upserted: usize,
+    /// the latest date having a value in "day-month-year" format, if the series has any value.
+    pub last_date: Option<String>,
+}
+
+
+/// mirrors data series into a local SQLite database.
+///
+/// The store keeps typed observations of each series and the last date having a value. Each
+/// [`sync`](fn@Store::sync) requests only the observations after the last synced date, together with a look-back
+/// window of days in which published values may have been revised, and inserts or updates them.
+///
+/// Dates are kept as the first days of their periods, e.g. the monthly observation of "2011-12" is kept as
+/// "01-12-2011".
+///
+/// This module is available with the `store` feature.
+///
+/// # Example
+///
+/// ```
+/// # use std::error::Error;
+/// #   use tcmb_evds::common::{Evds, ApiKey, ReturnFormat};
+///     use tcmb_evds::date::DateRange;
+///     use tcmb_evds::store::Store;
+///
+/// # fn main() -> Result<(), Box<dyn Error>> {
+/// #   let api_key =
+/// #       if let Ok(api_key) = ApiKey::from("users_api_key".to_string()) { api_key }
+/// #       else { return Ok(()) };
+/// #
+/// #   let evds = Evds::from(api_key, ReturnFormat::Json);
+///     let mut store = Store::from("evds.sqlite")?;
+///
+///     store.change_history_start("01-01-2015")?;
+///     store.change_look_back_days(14);
+///
+///     // the first call requests the whole history and the following calls request only the missing tail.
+///     store.sync(&["TP.DK.USD.A", "TP.DK.EUR.A"], &evds)?;
+///
+///     let observations = store.read("TP.DK.USD.A", &DateRange::from("01-01-2020", "31-12-2020")?)?;
+/// #   Ok(())
+/// # }
+/// ```
+pub struct Store {
+    connection: Connection,
+    history_start: Day,
+    look_back_days: u32,
+    track_vintages: bool,
+}
+
+impl Store {
+    /// opens or creates the database at the given path.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be opened or initialised.
+    pub fn from<P: AsRef<Path>>(path: P) -> Result<Store, ReturnError> {
+        Store::initialise(Connection::open(path).map_err(to_store_error)?)
+    }
+
+    /// creates a database living in memory until the store is dropped.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be initialised.
+    pub fn in_memory() -> Result<Store, ReturnError> {
+        Store::initialise(Connection::open_in_memory().map_err(to_store_error)?)
+    }
+
+    fn initialise(connection: Connection) -> Result<Store, ReturnError> {
+        connection.execute_batch(SCHEMA).map_err(to_store_error)?;
+
+        Ok(Store {
+            connection,
+            history_start: Day { year: 1950, month: 1, day: 1 },
+            look_back_days: 7,
+            track_vintages: false,
+        })
+    }
+
+    /// changes the first date requested for a series that has never been synced. Default is "01-01-1950".
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the date is not in "day-month-year" format.
+    pub fn change_history_start(&mut self, history_start: &str) -> Result<(), ReturnError> {
+        self.history_start = Day::parse(history_start).ok_or(ReturnError::InvalidDate)?;
+
+        Ok(())
+    }
+
+    /// changes the number of days before the last synced date that are requested again to catch revisions.
+    ///
+    /// Default is 7 days.
+    pub fn change_look_back_days(&mut self, look_back_days: u32) {
+        self.look_back_days = look_back_days;
+    }
+
+    /// changes whether each fetched value is also kept with its retrieval time. Default is false.
+    ///
+    /// A value is kept as a new vintage only when it differs from the latest kept vintage of its date. Vintages make
+    /// [`read_as_of`](fn@Store::read_as_of) and [`revisions`](fn@Store::revisions) possible.
+    pub fn change_vintage_tracking(&mut self, track_vintages: bool) {
+        self.track_vintages = track_vintages;
+    }
+
+    /// gives the latest synced date having a value in "day-month-year" format.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be read.
+    pub fn last_synced_date(&self, series: &str) -> Result<Option<String>, ReturnError> {
+        Ok(last_synced_day(&self.connection, series.trim())?.map(Day::format))
+    }
+
+    /// requests the missing observations of each series and inserts or updates them.
+    ///
+    /// Series having the same first missing date are requested together.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if a request fails or the database cannot be written. Series synced before the
+    /// failure are kept.
+    pub fn sync(&mut self, series_set: &[&str], evds: &Evds) -> Result<Vec<SeriesSync>, ReturnError> {
+        self.sync_with(series_set, calendar::today_in_istanbul(), |data_series, date_range| {
+            evds_basic::get_observations(data_series, &DatePreference::Multiple(date_range), evds)
+        })
+    }
+
+    fn sync_with<F>(&mut self, series_set: &[&str], today: Day, fetch: F) -> Result<Vec<SeriesSync>, ReturnError>
+    where
+        F: Fn(&str, DateRange) -> Result<Observations, ReturnError>,
+    {
+        let mut groups: BTreeMap<Day, Vec<String>> = BTreeMap::new();
+
+        for series in series_set {
+            let series = series.trim().to_string();
+
+            let start = match last_synced_day(&self.connection, &series)? {
+                Some(last_day) => last_day.add_days(-(self.look_back_days as i64)).max(self.history_start),
+                None => self.history_start,
+            };
+
+            groups.entry(start.min(today)).or_default().push(series);
+        }
+
+        let mut results = Vec::new();
+        let retrieval = self.retrieval();
+
+        for (start, group) in groups {
+            let date_range = DateRange::from(&start.format(), &today.format())?;
+            let observations = fetch(&group.join("-"), date_range)?;
+
+            let transaction = self.connection.transaction().map_err(to_store_error)?;
+
+            for series in group {
+                let upserted = upsert_series(&transaction, &series, &observations, retrieval)?;
+
+                results.push(SeriesSync {
+                    last_date: last_synced_day(&transaction, &series)?.map(Day::format),
+                    series,
+                    start_date: start.format(),
+                    upserted,
+                });
+            }
+
+            transaction.commit().map_err(to_store_error)?;
+        }
+
+        Ok(results)
+    }
+
+    /// gives the time of a retrieval and whether its vintages are kept.
+    fn retrieval(&self) -> Retrieval {
+        Retrieval { retrieved_at: seconds_now(), track_vintages: self.track_vintages }
+    }
+
+    /// checks the stored observations of the series over the date range against its declared frequency.
+    ///
+    /// Follow [`completeness::check`](fn@crate::completeness::check) for details of the report.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be read or the dates of the range are invalid.
+    pub fn check_completeness(
+        &self,
+        series: &str,
+        data_frequency: DataFrequency,
+        date_range: &DateRange,
+        business_calendar: &BusinessCalendar,
+    ) -> Result<CompletenessReport, ReturnError> {
+        let observations = self.read(series, date_range)?;
+
+        completeness::check(&observations, series, data_frequency, date_range, business_calendar)
+    }
+
+    /// checks the stored observations of the series of the metadata over the date range against the frequency given
+    /// in the metadata, such as the metadata of [`get_series_metadata`](fn@crate::evds_basic::get_series_metadata).
+    ///
+    /// # Error
+    ///
+    /// This function returns the errors of [`check_completeness`](fn@Store::check_completeness) and an error if the
+    /// frequency of the metadata is not known.
+    pub fn check_completeness_with_metadata(
+        &self,
+        series_metadata: &SeriesMetadata,
+        date_range: &DateRange,
+        business_calendar: &BusinessCalendar,
+    ) -> Result<CompletenessReport, ReturnError> {
+        let data_frequency = series_metadata.data_frequency().ok_or_else(|| {
+            ReturnError::UnknownFrequency(series_metadata.frequency.clone().unwrap_or_default())
+        })?;
+
+        self.check_completeness(&series_metadata.code, data_frequency, date_range, business_calendar)
+    }
+
+    /// requests the gaps of the report again and inserts or updates their observations.
+    ///
+    /// Each gap range of the report is requested separately, so that only the missing parts are requested. The number
+    /// of inserted or updated observations is returned.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if a request fails or the database cannot be written. Gaps filled before the
+    /// failure are kept.
+    pub fn refetch_gaps(
+        &mut self,
+        series: &str,
+        report: &CompletenessReport,
+        evds: &Evds,
+    ) -> Result<usize, ReturnError> {
+        self.refetch_gaps_with(series, report, |data_series, date_range| {
+            evds_basic::get_observations(data_series, &DatePreference::Multiple(date_range), evds)
+        })
+    }
+
+    fn refetch_gaps_with<F>(&mut self, series: &str, report: &CompletenessReport, fetch: F) -> Result<usize, ReturnError>
+    where
+        F: Fn(&str, DateRange) -> Result<Observations, ReturnError>,
+    {
+        let series = series.trim();
+        let mut upserted = 0;
+        let retrieval = self.retrieval();
+
+        for date_range in report.gap_ranges() {
+            let observations = fetch(series, date_range)?;
+
+            let transaction = self.connection.transaction().map_err(to_store_error)?;
+            upserted += upsert_series(&transaction, series, &observations, retrieval)?;
+            transaction.commit().map_err(to_store_error)?;
+        }
+
+        Ok(upserted)
+    }
+
+    /// gives the stored observations of the series in the date range.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be read.
+    pub fn read(&self, series: &str, date_range: &DateRange) -> Result<Observations, ReturnError> {
+        let (start_date, end_date) = date_range.get_dates();
+
+        let start = Day::parse(start_date).ok_or(ReturnError::InvalidDate)?;
+        let end = Day::parse(end_date).ok_or(ReturnError::InvalidDate)?;
+
+        let mut statement = self.connection
+            .prepare("SELECT date, value FROM observations WHERE series = ?1 AND date BETWEEN ?2 AND ?3 ORDER BY date")
+            .map_err(to_store_error)?;
+
+        let rows = statement
+            .query_map(params![series.trim(), start.format_iso(), end.format_iso()], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Option<f64>>(1)?))
+            })
+            .map_err(to_store_error)?
+            .map(|row| {
+                let (date, value) = row.map_err(to_store_error)?;
+                let day = Day::parse_iso(&date).ok_or(ReturnError::InvalidDate)?;
+
+                Ok(Observation { date: day.format(), values: vec![value] })
+            })
+            .collect::<Result<Vec<Observation>, ReturnError>>()?;
+
+        Ok(Observations { series: vec![response::series_name(series)], rows })
+    }
+}
+
+
+/// is the time of a retrieval in seconds since 01-01-1970 and whether its vintages are kept.
+#[derive(Clone, Copy)]
+struct Retrieval {
+    retrieved_at: i64,
+    track_vintages: bool,
+}
+
+
+/// inserts or updates the observations of the series, moves its last synced date forward and gives the number of
+/// observations.
+fn upsert_series(
+    connection: &Connection,
+    series: &str,
+    observations: &Observations,
+    retrieval: Retrieval,
+) -> Result<usize, ReturnError> {
+    let index = match observations.series_index(series) {
+        Some(index) => index,
+        None => return Ok(0),
+    };
+
+    let mut statement = connection
+        .prepare_cached(
+            "INSERT INTO observations (series, date, value) VALUES (?1, ?2, ?3)
+            ON CONFLICT (series, date) DO UPDATE SET value = excluded.value",
+        )
+        .map_err(to_store_error)?;
+
+    let mut last_day = None;
+
+    for row in &observations.rows {
+        let day = Day::parse_period(&row.date).ok_or(ReturnError::UnableToParseResponse)?;
+        let value = row.values[index];
+
+        statement.execute(params![series, day.format_iso(), value]).map_err(to_store_error)?;
+
+        if retrieval.track_vintages {
+            vintage::record(connection, series, day, value, retrieval.retrieved_at)?;
+        }
+
+        if value.is_some() { last_day = last_day.max(Some(day)); }
+    }
+
+    if let Some(last_day) = last_day {
+        connection.execute(
+            "INSERT INTO sync_state (series, last_date, synced_at) VALUES (?1, ?2, ?3)
+            ON CONFLICT (series) DO UPDATE SET
+                last_date = MAX(last_date, excluded.last_date), synced_at = excluded.synced_at",
+            params![series, last_day.format_iso(), retrieval.retrieved_at],
+        ).map_err(to_store_error)?;
+    }
+
+    Ok(observations.rows.len())
+}
+
+/// gives the latest synced date having a value.
+fn last_synced_day(connection: &Connection, series: &str) -> Result<Option<Day>, ReturnError> {
+    let last_date: Option<String> = connection
+        .query_row("SELECT last_date FROM sync_state WHERE series = ?1", params![series], |row| row.get(0))
+        .optional()
+        .map_err(to_store_error)?;
+
+    Ok(last_date.as_deref().and_then(Day::parse_iso))
+}
+
+fn to_store_error(error: rusqlite::Error) -> ReturnError {
+    ReturnError::StoreError(error.to_string())
+}
+
+fn seconds_now() -> i64 {
+    match SystemTime::now().duration_since(UNIX_EPOCH) {
+        Ok(duration) => duration.as_secs() as i64,
+        Err(_) => 0,
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+    use crate::common::ReturnFormat;
+
+    fn observations(series: &[&str], rows: &[(&str, &[Option<f64>])]) -> Observations {
+        Observations {
+            series: series.iter().map(|series| response::series_name(series)).collect(),
+            rows: rows.iter()
+                .map(|(date, values)| Observation { date: date.to_string(), values: values.to_vec() })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn should_request_missing_tail_with_look_back() {
+        let mut store = Store::in_memory().unwrap();
+        store.change_history_start("01-01-2020").unwrap();
+        store.change_look_back_days(1);
+
+        let requests = RefCell::new(Vec::new());
+        let today = Day::parse("10-01-2020").unwrap();
+
+        let first = store.sync_with(&["TP.DK.USD.A", "TP.DK.EUR.A"], today, |data_series, date_range| {
+            requests.borrow_mut().push((data_series.to_string(), date_range.get_dates().0.to_string()));
+
+            Ok(observations(
+                &["TP.DK.USD.A", "TP.DK.EUR.A"],
+                &[("02-01-2020", &[Some(5.9), Some(6.6)]), ("03-01-2020", &[Some(5.95), None])],
+            ))
+        }).unwrap();
+
+        assert_eq!(Some("03-01-2020".to_string()), first[0].last_date);
+        assert_eq!(Some("02-01-2020".to_string()), first[1].last_date);
+        assert_eq!(2, first[0].upserted);
+
+        let today = Day::parse("13-01-2020").unwrap();
+
+        store.sync_with(&["TP.DK.USD.A", "TP.DK.EUR.A"], today, |data_series, date_range| {
+            requests.borrow_mut().push((data_series.to_string(), date_range.get_dates().0.to_string()));
+
+            Ok(observations(&[data_series], &[("03-01-2020", &[Some(5.97)]), ("10-01-2020", &[Some(6.0)])]))
+        }).unwrap();
+
+        assert_eq!(
+            vec![
+                ("TP.DK.USD.A-TP.DK.EUR.A".to_string(), "01-01-2020".to_string()),
+                ("TP.DK.EUR.A".to_string(), "01-01-2020".to_string()),
+                ("TP.DK.USD.A".to_string(), "02-01-2020".to_string()),
+            ],
+            requests.into_inner()
+        );
+
+        let date_range = DateRange::from("01-01-2020", "31-01-2020").unwrap();
+        let usd = store.read("TP.DK.USD.A", &date_range).unwrap();
+
+        assert_eq!(Some(5.97), usd.value("03-01-2020", "TP.DK.USD.A"));
+        assert_eq!(Some(6.0), usd.value("10-01-2020", "TP.DK.USD.A"));
+        assert_eq!(Some("10-01-2020".to_string()), store.last_synced_date("TP.DK.USD.A").unwrap());
+    }
+
+    #[test]
+    fn should_sync_empty_responses_without_observations() {
+        let mut store = Store::in_memory().unwrap();
+        store.change_history_start("01-01-2020").unwrap();
+        store.change_look_back_days(0);
+
+        let today = Day::parse("10-01-2020").unwrap();
+        let empty_response = |data_series: &str, _| {
+            Observations::parse_series(r#"{"items":[]}"#, &ReturnFormat::Json, data_series)
+        };
+
+        let synced = store.sync_with(&["TP.DK.USD.A", "TP.DK.EUR.A"], today, empty_response).unwrap();
+
+        assert_eq!(vec![0, 0], synced.iter().map(|series_sync| series_sync.upserted).collect::<Vec<usize>>());
+        assert_eq!(None, store.last_synced_date("TP.DK.USD.A").unwrap());
+    }
+
+    #[test]
+    fn should_refetch_only_gaps() {
+        let mut store = Store::in_memory().unwrap();
+        let today = Day::parse("31-12-2020").unwrap();
+
+        store.sync_with(&["TP.FG.J0"], today, |_, _| {
+            Ok(observations(&["TP.FG.J0"], &[("2020-1", &[Some(1.0)]), ("2020-2", &[None]), ("2020-4", &[Some(4.0)])]))
+        }).unwrap();
+
+        let date_range = DateRange::from("01-01-2020", "30-04-2020").unwrap();
+        let report = store
+            .check_completeness("TP.FG.J0", DataFrequency::Monthly, &date_range, &BusinessCalendar::new())
+            .unwrap();
+
+        assert_eq!(vec!["01-03-2020".to_string()], report.missing_periods);
+
+        let requests = RefCell::new(Vec::new());
+
+        let upserted = store.refetch_gaps_with("TP.FG.J0", &report, |data_series, date_range| {
+            let (start_date, end_date) = date_range.get_dates();
+            requests.borrow_mut().push((data_series.to_string(), start_date.to_string(), end_date.to_string()));
+
+            Ok(observations(&["TP.FG.J0"], &[("2020-2", &[Some(2.0)]), ("2020-3", &[Some(3.0)])]))
+        }).unwrap();
+
+        assert_eq!(2, upserted);
+        assert_eq!(
+            vec![("TP.FG.J0".to_string(), "01-02-2020".to_string(), "31-03-2020".to_string())],
+            requests.into_inner()
+        );
+        assert!(store
+            .check_completeness("TP.FG.J0", DataFrequency::Monthly, &date_range, &BusinessCalendar::new())
+            .unwrap()
+            .is_complete());
+    }
+
+    #[test]
+    fn should_check_completeness_with_frequency_of_metadata() {
+        let mut store = Store::in_memory().unwrap();
+        let today = Day::parse("31-12-2020").unwrap();
+
+        store.sync_with(&["TP.FG.J0"], today, |_, _| {
+            Ok(observations(&["TP.FG.J0"], &[("2020-1", &[Some(1.0)]), ("2020-3", &[Some(3.0)])]))
+        }).unwrap();
+
+        let mut series_metadata = SeriesMetadata {
+            code: "TP.FG.J0".to_string(),
+            data_group: None,
+            name: None,
+            name_eng: None,
+            unit: None,
+            frequency: Some("AYLIK".to_string()),
+            start_date: None,
+            end_date: None,
+        };
+        let date_range = DateRange::from("01-01-2020", "31-03-2020").unwrap();
+
+        let report = store
+            .check_completeness_with_metadata(&series_metadata, &date_range, &BusinessCalendar::turkish())
+            .unwrap();
+
+        assert_eq!(vec!["01-02-2020".to_string()], report.missing_periods);
+
+        series_metadata.frequency = Some("DÜZENSİZ".to_string());
+
+        assert_eq!(
+            Err(ReturnError::UnknownFrequency("DÜZENSİZ".to_string())),
+            store.check_completeness_with_metadata(&series_metadata, &date_range, &BusinessCalendar::turkish())
+        );
+    }
+
+    #[test]
+    fn should_keep_periods_as_first_days() {
+        let mut store = Store::in_memory().unwrap();
+        let today = Day::parse("15-03-2021").unwrap();
+
+        store.sync_with(&["TP.FG.J0"], today, |_, _| {
+            Ok(observations(&["TP.FG.J0"], &[("2021-1", &[Some(1.68)]), ("2021-2", &[Some(0.91)]), ("2021-3", &[None])]))
+        }).unwrap();
+
+        let date_range = DateRange::from("01-01-2021", "31-12-2021").unwrap();
+        let stored = store.read("TP.FG.J0", &date_range).unwrap();
+
+        assert_eq!(3, stored.rows.len());
+        assert_eq!(Some(0.91), stored.value("01-02-2021", "TP.FG.J0"));
+        assert_eq!(Some("01-02-2021".to_string()), store.last_synced_date("TP.FG.J0").unwrap());
+    }
+}
@@ -0,0 +1,254 @@
+use std::collections::BTreeMap;
+
+use rusqlite::{params, Connection};
+
+use super::{to_store_error, Store};
+use crate::date::calendar::{Day, ISTANBUL_UTC_OFFSET, SECONDS_PER_DAY};
+use crate::date::DateRange;
+use crate::error::ReturnError;
+use crate::response::{self, Observation, Observations};
+
+
+/// is an observation whose value changed between two retrievals.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Revision {
+    /// date of the observation in "day-month-year" format.
+    pub date: String,
+    /// value as of the earlier retrieval.
+    pub previous: Option<f64>,
+    /// value as of the later retrieval.
+    pub revised: Option<f64>,
+}
+
+
+/// keeps the value as a new vintage unless it is the same as the latest vintage of its date.
+pub(super) fn record(
+    connection: &Connection,
+    series: &str,
+    day: Day,
+    value: Option<f64>,
+    retrieved_at: i64,
+) -> Result<(), ReturnError> {
+    let mut statement = connection
+        .prepare_cached(
+            "INSERT OR REPLACE INTO vintages (series, date, value, retrieved_at)
+            SELECT ?1, ?2, ?3, ?4
+            WHERE NOT EXISTS (
+                SELECT 1 FROM vintages AS latest
+                WHERE latest.series = ?1 AND latest.date = ?2 AND latest.value IS ?3 AND latest.retrieved_at = (
+                    SELECT MAX(retrieved_at) FROM vintages WHERE series = ?1 AND date = ?2
+                )
+            )",
+        )
+        .map_err(to_store_error)?;
+
+    statement.execute(params![series, day.format_iso(), value, retrieved_at]).map_err(to_store_error)?;
+
+    Ok(())
+}
+
+
+impl Store {
+    /// gives the retrieval times of the series that kept at least a vintage, in seconds since 01-01-1970.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be read.
+    pub fn retrievals(&self, series: &str) -> Result<Vec<i64>, ReturnError> {
+        let mut statement = self.connection
+            .prepare("SELECT DISTINCT retrieved_at FROM vintages WHERE series = ?1 ORDER BY retrieved_at")
+            .map_err(to_store_error)?;
+
+        let retrievals = statement
+            .query_map(params![series.trim()], |row| row.get::<_, i64>(0))
+            .map_err(to_store_error)?
+            .collect::<Result<Vec<i64>, rusqlite::Error>>()
+            .map_err(to_store_error)?;
+
+        Ok(retrievals)
+    }
+
+    /// gives the series in the date range as it was known at the end of the given day in Istanbul time.
+    ///
+    /// Only values retrieved while vintage tracking is on are taken into account.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be read or a date is not in "day-month-year" format.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::error::Error;
+    ///     use tcmb_evds::date::DateRange;
+    ///     use tcmb_evds::store::Store;
+    ///
+    /// # fn main() -> Result<(), Box<dyn Error>> {
+    ///     let mut store = Store::in_memory()?;
+    ///
+    ///     store.change_vintage_tracking(true);
+    ///
+    ///     // ... syncs on different days ...
+    ///
+    ///     let date_range = DateRange::from("01-01-2020", "31-12-2020")?;
+    ///     let first_release = store.read_as_of("TP.FG.J0", &date_range, "15-02-2021")?;
+    ///
+    ///     let retrievals = store.retrievals("TP.FG.J0")?;
+    ///
+    ///     if let [first, .., last] = retrievals.as_slice() {
+    ///         for revision in store.revisions("TP.FG.J0", &date_range, *first, *last)? {
+    ///             println!("{}: {:?} -> {:?}", revision.date, revision.previous, revision.revised);
+    ///         }
+    ///     }
+    /// #   Ok(())
+    /// # }
+    /// ```
+    pub fn read_as_of(&self, series: &str, date_range: &DateRange, as_of: &str) -> Result<Observations, ReturnError> {
+        let as_of = Day::parse(as_of).ok_or(ReturnError::InvalidDate)?;
+        let end_of_day = (as_of.to_days() + 1) * SECONDS_PER_DAY - ISTANBUL_UTC_OFFSET - 1;
+
+        self.read_retrieved(series, date_range, end_of_day)
+    }
+
+    /// gives the series in the date range as it was known at the given retrieval time.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be read or a date is not in "day-month-year" format.
+    pub fn read_retrieved(
+        &self,
+        series: &str,
+        date_range: &DateRange,
+        retrieved_at: i64,
+    ) -> Result<Observations, ReturnError> {
+        let rows = self.vintages_at(series, date_range, retrieved_at)?
+            .into_iter()
+            .map(|(day, value)| Observation { date: day.format(), values: vec![value] })
+            .collect();
+
+        Ok(Observations { series: vec![response::series_name(series)], rows })
+    }
+
+    /// gives the observations in the date range whose values differ between the two retrieval times.
+    ///
+    /// Observations first published after the earlier retrieval are not revisions, therefore they are not given.
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the database cannot be read or a date is not in "day-month-year" format.
+    pub fn revisions(
+        &self,
+        series: &str,
+        date_range: &DateRange,
+        earlier: i64,
+        later: i64,
+    ) -> Result<Vec<Revision>, ReturnError> {
+        let previous = self.vintages_at(series, date_range, earlier)?;
+        let revised = self.vintages_at(series, date_range, later)?;
+
+        let revisions = previous.into_iter()
+            .filter_map(|(day, previous)| match revised.get(&day) {
+                Some(revised) if *revised != previous => {
+                    Some(Revision { date: day.format(), previous, revised: *revised })
+                },
+                _ => None,
+            })
+            .collect();
+
+        Ok(revisions)
+    }
+
+    /// gives the latest vintage of each date in the range retrieved until the given time.
+    fn vintages_at(
+        &self,
+        series: &str,
+        date_range: &DateRange,
+        retrieved_at: i64,
+    ) -> Result<BTreeMap<Day, Option<f64>>, ReturnError> {
+        let (start_date, end_date) = date_range.get_dates();
+
+        let start = Day::parse(start_date).ok_or(ReturnError::InvalidDate)?;
+        let end = Day::parse(end_date).ok_or(ReturnError::InvalidDate)?;
+
+        let mut statement = self.connection
+            .prepare(
+                "SELECT vintage.date, vintage.value FROM vintages AS vintage
+                WHERE vintage.series = ?1 AND vintage.date BETWEEN ?2 AND ?3 AND vintage.retrieved_at = (
+                    SELECT MAX(retrieved_at) FROM vintages
+                    WHERE series = vintage.series AND date = vintage.date AND retrieved_at <= ?4
+                )",
+            )
+            .map_err(to_store_error)?;
+
+        let rows = statement
+            .query_map(params![series.trim(), start.format_iso(), end.format_iso(), retrieved_at], |row| {
+                Ok((row.get::<_, String>(0)?, row.get::<_, Option<f64>>(1)?))
+            })
+            .map_err(to_store_error)?;
+
+        rows.map(|row| {
+            let (date, value) = row.map_err(to_store_error)?;
+
+            Ok((Day::parse_iso(&date).ok_or(ReturnError::InvalidDate)?, value))
+        }).collect()
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::super::Retrieval;
+    use super::*;
+
+    fn save(store: &mut Store, rows: &[(&str, Option<f64>)], retrieved_at: i64) {
+        let observations = Observations {
+            series: vec!["TP_FG_J0".to_string()],
+            rows: rows.iter()
+                .map(|(date, value)| Observation { date: date.to_string(), values: vec![*value] })
+                .collect(),
+        };
+
+        let retrieval = Retrieval { retrieved_at, track_vintages: store.track_vintages };
+
+        super::super::upsert_series(&store.connection, "TP.FG.J0", &observations, retrieval).unwrap();
+    }
+
+    #[test]
+    fn should_answer_as_of_and_revisions() {
+        let mut store = Store::in_memory().unwrap();
+        store.change_vintage_tracking(true);
+
+        // 15-02-2021 12:00 and 15-03-2021 12:00 in Istanbul time.
+        let february = 18_673 * SECONDS_PER_DAY + 9 * 60 * 60;
+        let march = february + 28 * SECONDS_PER_DAY;
+
+        save(&mut store, &[("2020-12", Some(1.25)), ("2021-1", Some(1.68))], february);
+        save(&mut store, &[("2020-12", Some(1.25)), ("2021-1", Some(1.70)), ("2021-2", Some(0.91))], march);
+
+        assert_eq!(vec![february, march], store.retrievals("TP.FG.J0").unwrap());
+
+        let date_range = DateRange::from("01-12-2020", "31-12-2021").unwrap();
+
+        let first_release = store.read_as_of("TP.FG.J0", &date_range, "15-02-2021").unwrap();
+        assert_eq!(2, first_release.rows.len());
+        assert_eq!(Some(1.68), first_release.value("01-01-2021", "TP.FG.J0"));
+
+        let latest = store.read_as_of("TP.FG.J0", &date_range, "16-03-2021").unwrap();
+        assert_eq!(Some(1.70), latest.value("01-01-2021", "TP.FG.J0"));
+        assert_eq!(Some(0.91), latest.value("01-02-2021", "TP.FG.J0"));
+
+        assert_eq!(
+            vec![Revision { date: "01-01-2021".to_string(), previous: Some(1.68), revised: Some(1.70) }],
+            store.revisions("TP.FG.J0", &date_range, february, march).unwrap()
+        );
+    }
+
+    #[test]
+    fn should_not_keep_vintages_unless_tracking() {
+        let mut store = Store::in_memory().unwrap();
+
+        save(&mut store, &[("2021-1", Some(1.68))], 100);
+
+        assert!(store.retrievals("TP.FG.J0").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,108 @@
+use std::io::{BufRead, BufReader, Write};
+use std::net::{TcpListener, TcpStream};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::thread;
+
+
+/// is a local HTTP server answering requests with scripted status codes and bodies.
+///
+/// Responses are given in order and the last one is repeated when the script is exhausted. An empty script makes the
+/// server echo each requested path back with status 200. Connections are kept alive so that the number of accepted
+/// connections can be compared with the number of served requests.
+pub(crate) struct StubServer {
+    port: u16,
+    requests: Arc<Mutex<Vec<String>>>,
+    connections: Arc<AtomicUsize>,
+}
+
+impl StubServer {
+    pub(crate) fn start(script: Vec<(u32, &str)>) -> StubServer {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("stub server should bind a local port");
+        let port = listener.local_addr().expect("stub server should have a local address").port();
+
+        let script: Arc<Vec<(u32, String)>> =
+            Arc::new(script.into_iter().map(|(code, body)| (code, body.to_string())).collect());
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let connections = Arc::new(AtomicUsize::new(0));
+
+        let server = StubServer { port, requests: requests.clone(), connections: connections.clone() };
+
+        thread::spawn(move || {
+            for stream in listener.incoming().flatten() {
+                connections.fetch_add(1, Ordering::SeqCst);
+
+                let script = script.clone();
+                let requests = requests.clone();
+
+                thread::spawn(move || serve(stream, &script, &requests));
+            }
+        });
+
+        server
+    }
+
+    pub(crate) fn url(&self) -> String {
+        format!("http://127.0.0.1:{}/", self.port)
+    }
+
+    pub(crate) fn request_count(&self) -> usize {
+        self.requests.lock().unwrap().len()
+    }
+
+    pub(crate) fn connection_count(&self) -> usize {
+        self.connections.load(Ordering::SeqCst)
+    }
+
+    /// gives requested paths in arrival order.
+    pub(crate) fn requested_paths(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+fn serve(stream: TcpStream, script: &[(u32, String)], requests: &Mutex<Vec<String>>) {
+    let mut writer = match stream.try_clone() {
+        Ok(writer) => writer,
+        Err(_) => return,
+    };
+    let mut reader = BufReader::new(stream);
+
+    loop {
+        let mut request_line = String::new();
+        match reader.read_line(&mut request_line) {
+            Ok(0) | Err(_) => return,
+            Ok(_) => {},
+        }
+
+        // Headers are skipped until the empty line closing the request.
+        loop {
+            let mut header = String::new();
+            match reader.read_line(&mut header) {
+                Ok(0) | Err(_) => return,
+                Ok(_) => if header == "\r\n" || header == "\n" { break },
+            }
+        }
+
+        let path = request_line.split(' ').nth(1).unwrap_or("").to_string();
+
+        let index = {
+            let mut requests = requests.lock().unwrap();
+            requests.push(path.clone());
+            requests.len() - 1
+        };
+
+        let (code, body) = match script.get(index.min(script.len().saturating_sub(1))) {
+            Some((code, body)) => (*code, body.as_str()),
+            None => (200, path.as_str()),
+        };
+
+        let response = format!(
+            "HTTP/1.1 {} Stub\r\nContent-Length: {}\r\nConnection: keep-alive\r\n\r\n{}",
+            code,
+            body.len(),
+            body
+        );
+
+        if writer.write_all(response.as_bytes()).is_err() { return }
+    }
+}
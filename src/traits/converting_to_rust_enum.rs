@@ -1,6 +1,11 @@
 use super::enum_specific::EnumSpecific;
+use crate::error::ReturnError;
 
 pub(crate) trait ConvertingToRustEnum<T: EnumSpecific> {
     /// converts implemented type to an enum type declared with T.
-    fn convert(&self) -> T;
+    ///
+    /// # Error
+    ///
+    /// This function returns an error if the value does not correspond to any option of T.
+    fn convert(&self) -> Result<T, ReturnError>;
 }
@@ -96,17 +96,17 @@ pub(crate) trait MakingUrlFormat {
         buffer
     }
 
-    /// generates url format combination of given two currency series.  
+    /// generates url format combination of given currency series.  
     ///
-    /// This function should be used when double exchange type is provided.
+    /// This function should be used when more than one exchange type is provided.
     /// 
     /// Buying and Selling Types Output Examples: 
     /// ``` Example
-    ///     Buying: TP.DK.A.JPY, Selling: TP.DK.S.JPY
+    ///     Buying: TP.DK.JPY.A, Selling: TP.DK.JPY.S, Effective Buying: TP.DK.JPY.A.EF
     /// 
-    ///     Combined_series: TP.DK.A.JPY-TP.DK.S.JPY
+    ///     Combined_series: TP.DK.JPY.A-TP.DK.JPY.S-TP.DK.JPY.A.EF
     /// ```
-    fn generate_two_combined_currencies_format(former_currency: &str, latter_currency: &str) -> String {
-        format!("series={}-{}", former_currency, latter_currency)
+    fn generate_combined_currencies_format(currencies: Vec<String>) -> String {
+        format!("series={}", currencies.join("-"))
     }
 }